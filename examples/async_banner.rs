@@ -0,0 +1,16 @@
+// --- examples/async_banner.rs ---
+// Ejecutar con: cargo run --example async_banner --features async
+use std::time::Duration;
+use vornix_banner::presets::ferris::walking_ferris;
+
+#[tokio::main]
+async fn main() -> Result<(), vornix_banner::BannerError> {
+    let mut banner = walking_ferris();
+
+    // La animación corre sin bloquear el runtime; al vencer el timeout el
+    // future se suelta y la terminal queda restaurada.
+    if tokio::time::timeout(Duration::from_secs(3), banner.display_async()).await.is_err() {
+        println!("Servicio listo.");
+    }
+    Ok(())
+}
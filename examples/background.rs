@@ -0,0 +1,20 @@
+// --- examples/background.rs ---
+use vornix_banner::{presets::ferris::walking_ferris, ScreenMode};
+use std::thread;
+use std::time::Duration;
+
+fn main() -> Result<(), vornix_banner::BannerError> {
+    // Ferris camina mientras la aplicación "carga" su configuración.
+    let handle = walking_ferris().screen_mode(ScreenMode::Inline).display_in_background();
+
+    thread::sleep(Duration::from_secs(2)); // Carga simulada
+    handle.pause();
+    thread::sleep(Duration::from_millis(500));
+    handle.resume();
+    thread::sleep(Duration::from_secs(1));
+
+    handle.stop();
+    handle.join()?;
+    println!("Configuración cargada.");
+    Ok(())
+}
@@ -0,0 +1,19 @@
+// --- examples/capabilities.rs ---
+use vornix_banner::{Banner, TerminalCapabilities};
+use std::io;
+use std::time::Duration;
+
+fn main() -> io::Result<()> {
+    let detected = TerminalCapabilities::detect();
+    println!("Detectado (entorno y terminfo): {:#?}", detected);
+
+    // Preguntar a la terminal afina la detección (nombre, sixel...).
+    let probed = detected.probe(Duration::from_millis(150));
+    println!("Tras consultar a la terminal: {:#?}", probed);
+
+    // Simular una terminal sin Unicode ni pantalla alternativa.
+    TerminalCapabilities::set_override(Some(TerminalCapabilities { unicode: false, alternate_screen: false, ..probed }));
+    Banner::new("caps").with_border(vornix_banner::BorderStyle::Rounded).display()?;
+    TerminalCapabilities::set_override(None);
+    Ok(())
+}
@@ -0,0 +1,17 @@
+// --- examples/export_cast.rs ---
+// Exporta el banner de arranque como grabación de asciinema para la documentación:
+//   cargo run --example export_cast && asciinema play inicio.cast
+use std::fs::File;
+use std::io::BufWriter;
+use vornix_banner::{Banner, BorderStyle, Reveal, RevealEffect, RUST_ORANGE};
+
+fn main() -> Result<(), vornix_banner::BannerError> {
+    let file = BufWriter::new(File::create("inicio.cast")?);
+    Banner::new("Vornix")
+        .with_foreground(RUST_ORANGE)
+        .with_border(BorderStyle::Rounded)
+        .with_reveal(Reveal::new(RevealEffect::Typewriter))
+        .to_asciicast(file)?;
+    println!("Grabación escrita en inicio.cast");
+    Ok(())
+}
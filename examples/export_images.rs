@@ -0,0 +1,18 @@
+// --- examples/export_images.rs ---
+// Genera la imagen de vista previa y el GIF de las notas de versión en CI:
+//   cargo run --example export_images --features raster
+use std::fs::File;
+use std::io::BufWriter;
+use vornix_banner::{presets::ferris::walking_ferris, Banner, BorderStyle, RasterOptions, RUST_ORANGE};
+
+fn main() -> Result<(), vornix_banner::BannerError> {
+    Banner::new("Vornix")
+        .with_foreground(RUST_ORANGE)
+        .with_border(BorderStyle::Double)
+        .to_png(&RasterOptions::new(), BufWriter::new(File::create("preview.png")?))?;
+
+    walking_ferris()
+        .to_gif(&RasterOptions::new().scale(1), BufWriter::new(File::create("ferris.gif")?))?;
+    println!("Escritos preview.png y ferris.gif");
+    Ok(())
+}
@@ -0,0 +1,17 @@
+// --- examples/export_svg.rs ---
+// Regenera las imágenes de la documentación a partir del código:
+//   cargo run --example export_svg
+use std::fs::File;
+use std::io::BufWriter;
+use vornix_banner::{presets::ferris::walking_ferris, Banner, BorderStyle, Gradient, Style, rgb};
+
+fn main() -> Result<(), vornix_banner::BannerError> {
+    Banner::new("Vornix")
+        .with_style(Style::new().gradient(Gradient::horizontal(vec![rgb(255, 94, 77), rgb(255, 221, 87)])).bold())
+        .with_border(BorderStyle::Rounded)
+        .to_svg(BufWriter::new(File::create("banner.svg")?))?;
+
+    walking_ferris().to_svg(BufWriter::new(File::create("ferris.svg")?))?;
+    println!("Escritos banner.svg y ferris.svg");
+    Ok(())
+}
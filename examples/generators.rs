@@ -0,0 +1,24 @@
+// --- examples/generators.rs ---
+use vornix_banner::{Banner, BuiltinFont, FontSource, Generator, ScreenMode, Style, RUST_ORANGE};
+use std::io;
+
+fn main() -> io::Result<()> {
+    let (width, height) = terminal_size::terminal_size().map_or((60, 16), |(w, h)| (w.0.min(70), h.0.min(16)));
+    let font = FontSource::Builtin(BuiltinFont::Standard);
+
+    let generators = [
+        Generator::matrix_rain(),
+        Generator::starfield().density(0.3),
+        Generator::fireworks(),
+        Generator::confetti().density(0.4),
+    ];
+    for generator in generators {
+        // Misma semilla, mismo resultado en cada ejecución.
+        let animation = generator.area(width, height).seed(2024).reveal_text("Vornix", font.clone()).animation()?;
+        Banner::from_animation(animation)
+            .with_style(Style::new().fg(RUST_ORANGE))
+            .screen_mode(ScreenMode::AlternateScreen)
+            .display()?;
+    }
+    Ok(())
+}
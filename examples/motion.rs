@@ -0,0 +1,28 @@
+// --- examples/motion.rs ---
+use vornix_banner::{Animation, Banner, Easing, Keyframe, Motion, MotionPath, ScreenMode};
+use std::io;
+
+fn main() -> io::Result<()> {
+    let width = terminal_size::terminal_size().map_or(60, |(w, _)| w.0.min(60));
+
+    // Una pelota que rebota por un escenario de 8 filas.
+    let ball = Animation::new(vec![vec!["()".into()], vec!["<>".into()]], 60);
+    let bounce = Motion::new(ball, MotionPath::Bounce { start: (0, 0), velocity: (2, 1) })
+        .area(width, 8)
+        .steps(80)
+        .animation();
+    Banner::from_animation(bounce).screen_mode(ScreenMode::Inline).display()?;
+
+    // Un cohete que despega siguiendo keyframes con aceleración.
+    let rocket = Animation::new(vec![vec![" /\\".into(), "|==|".into(), " ** ".into()]], 50);
+    let path = MotionPath::Keyframes(vec![
+        Keyframe::new(0, 2, 7),
+        Keyframe::new(40, width as i32 - 6, 0).easing(Easing::EaseIn),
+    ]);
+    let launch = Motion::new(rocket, path).area(width, 10).animation();
+    Banner::from_animation(launch).screen_mode(ScreenMode::Inline).display()?;
+
+    // Ferris cruzando la pantalla (pulsa `q` para salir).
+    vornix_banner::presets::ferris::crossing_ferris(width).display()?;
+    Ok(())
+}
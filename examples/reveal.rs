@@ -0,0 +1,28 @@
+// --- examples/reveal.rs ---
+use vornix_banner::{Banner, BuiltinFont, Easing, Edge, Reveal, RevealEffect, Style, RUST_ORANGE};
+use std::io;
+use std::time::Duration;
+
+fn main() -> io::Result<()> {
+    let effects = [
+        RevealEffect::Typewriter,
+        RevealEffect::LineByLine,
+        RevealEffect::SlideIn(Edge::Left),
+        RevealEffect::Wipe(Edge::Bottom),
+        RevealEffect::Scramble,
+    ];
+
+    for effect in effects {
+        let mut banner = Banner::new("Vornix")
+            .with_builtin_font(BuiltinFont::Slant)
+            .with_style(Style::new().fg(RUST_ORANGE).bold())
+            .with_reveal(
+                Reveal::new(effect)
+                    .duration(Duration::from_millis(900))
+                    .easing(Easing::EaseOut),
+            );
+        banner.display()?;
+    }
+
+    Ok(())
+}
@@ -1,6 +1,6 @@
 // --- examples/styling_demo.rs ---
 use vornix_banner::{
-    Banner, BuiltinFont, Style,
+    Banner, BackgroundMode, BuiltinFont, Style,
     RUST_ORANGE, rgb // Helpers/Constantes
 };
 use std::io;
@@ -44,9 +44,22 @@ fn main() -> io::Result<()> {
                 .bg(rgb(50, 50, 50))    // Gris oscuro
                 .bold()
                 .underline()
-        );
+        )
+        .background_mode(BackgroundMode::Panel { margin_x: 2, margin_y: 1 }); // Fondo rectangular
     banner_combo.display()?;
 
+    println!("\nEstilo: Atributos SGR extendidos (tachado, subrayado ondulado con color)");
+    let mut banner_sgr = Banner::new("SGR")
+        .with_builtin_font(DEMO_FONT)
+        .with_style(
+            Style::new()
+                .fg(rgb(220, 220, 220))
+                .dim()
+                .curly_underline()
+                .underline_color(RUST_ORANGE)
+        );
+    banner_sgr.display()?;
+
     println!("\n--- Fin de la demostración de estilos ---");
     Ok(())
 }
\ No newline at end of file
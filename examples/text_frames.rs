@@ -0,0 +1,16 @@
+// --- examples/text_frames.rs ---
+// Carga una animación dibujada en un archivo de texto (o una grabación de asciinema).
+use vornix_banner::{Animation, Banner, RUST_ORANGE, Style};
+
+fn main() -> Result<(), vornix_banner::BannerError> {
+    let path = std::env::args().nth(1).unwrap_or_else(|| "examples/frames/guiño.txt".to_string());
+    let animation = if path.ends_with(".cast") {
+        Animation::from_asciicast_file(&path)?
+    } else {
+        Animation::from_text_file(&path)?.repeat(Some(3))
+    };
+
+    Banner::from_animation(animation)
+        .with_style(Style::new().fg(RUST_ORANGE))
+        .display()
+}
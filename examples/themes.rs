@@ -0,0 +1,21 @@
+// --- examples/themes.rs ---
+use vornix_banner::{Banner, Theme, ThemeRegistry};
+use std::io;
+
+fn main() -> io::Result<()> {
+    println!("--- Ejemplo: Temas Incrustados ---");
+
+    let registry = ThemeRegistry::with_builtins();
+    for name in registry.names() {
+        println!("\nTema: {}", name);
+        let theme = registry.get(name).expect("tema registrado");
+        let mut banner = Banner::new("Vornix").with_theme(theme);
+        banner.display()?;
+    }
+
+    // Un tema propio puede construirse en código o cargarse con `ThemeRegistry::load_file`.
+    let brand = Theme::builtin("sunset").expect("tema incrustado");
+    Banner::new("Brand").with_theme(&brand).display()?;
+
+    Ok(())
+}
@@ -4,6 +4,12 @@
 //! Define estructuras para animaciones basadas en frames predefinidos.
 
 use crate::banner::BannerContent;
+use crate::error::BannerError;
+use crate::hyperlink::HyperlinkRegion;
+use crate::keys::KeyBindings;
+use crate::layout::content_width;
+use crate::utils::get_line_width_accurate;
+use std::borrow::Cow;
 use std::time::Duration;
 
 /// Representa un único frame de una animación ASCII (un conjunto de líneas).
@@ -16,8 +22,20 @@ pub struct Animation {
     pub frames: Vec<Frame>,
     /// El tiempo a esperar entre la visualización de cada frame.
     pub frame_delay: Duration,
+    /// Duración propia de cada frame (mismo índice que `frames`). `None`, o un
+    /// índice fuera de rango, indica que se usa `frame_delay`.
+    pub frame_durations: Vec<Option<Duration>>,
+    /// Tiempo extra que se mantiene el último frame antes de terminar.
+    pub hold_last: Duration,
     /// Cuántas veces repetir la animación completa. `None` significa bucle infinito.
     pub repeat: Option<usize>,
+    /// Zonas de los frames que enlazan a una URL (OSC 8).
+    pub links: Vec<HyperlinkRegion>,
+    /// Teclas que controlan la reproducción (salir, pausa, paso a paso...).
+    pub key_bindings: KeyBindings,
+    /// Si es `true`, los frames se rellenan hasta un recuadro común antes de
+    /// mostrarse en lugar de rechazar dimensiones distintas.
+    pub normalize: bool,
 }
 
 impl Animation {
@@ -30,7 +48,12 @@ impl Animation {
         Self {
             frames,
             frame_delay: Duration::from_millis(frame_delay_ms),
+            frame_durations: Vec::new(),
+            hold_last: Duration::ZERO,
             repeat: Some(1), // Por defecto, reproducir la secuencia una vez
+            links: Vec::new(),
+            key_bindings: KeyBindings::default(),
+            normalize: false,
         }
     }
 
@@ -52,4 +75,302 @@ impl Animation {
         self.frame_delay = Duration::from_millis(ms);
         self
     }
-}
\ No newline at end of file
+
+    /// Asigna una duración propia al frame `index`, en lugar de `frame_delay`.
+    pub fn with_frame_duration(mut self, index: usize, duration: Duration) -> Self {
+        if self.frame_durations.len() <= index {
+            self.frame_durations.resize(index + 1, None);
+        }
+        self.frame_durations[index] = Some(duration);
+        self
+    }
+
+    /// Añade un frame al final, con duración propia opcional.
+    pub fn push_frame(&mut self, frame: Frame, duration: Option<Duration>) {
+        self.frame_durations.resize(self.frames.len(), None);
+        self.frames.push(frame);
+        self.frame_durations.push(duration);
+    }
+
+    /// Mantiene el último frame en pantalla durante `duration` antes de terminar
+    /// (solo al final de la última repetición).
+    pub fn hold_last(mut self, duration: Duration) -> Self {
+        self.hold_last = duration;
+        self
+    }
+
+    /// Tiempo que debe mostrarse el frame `index`.
+    pub fn delay_for(&self, index: usize) -> Duration {
+        self.frame_durations.get(index).copied().flatten().unwrap_or(self.frame_delay)
+    }
+
+    /// Añade una zona clicable (hipervínculo OSC 8) común a todos los frames.
+    pub fn with_link(mut self, region: HyperlinkRegion) -> Self {
+        self.links.push(region);
+        self
+    }
+
+    /// Sustituye las teclas que controlan la reproducción.
+    pub fn with_key_bindings(mut self, bindings: KeyBindings) -> Self {
+        self.key_bindings = bindings;
+        self
+    }
+
+    /// Rellena automáticamente los frames hasta un recuadro común (ver `normalized`).
+    pub fn normalize_frames(mut self, enabled: bool) -> Self {
+        self.normalize = enabled;
+        self
+    }
+
+    // --- Validación ---
+
+    /// Comprueba que la animación se puede reproducir: que tenga frames, que
+    /// un bucle infinito no tenga todas sus duraciones a cero y, salvo que
+    /// `normalize` esté activo, que todos los frames midan lo mismo (alto y
+    /// ancho máximo). Los restos de un frame más grande quedarían en pantalla.
+    pub fn validate(&self) -> Result<(), BannerError> {
+        if self.frames.is_empty() {
+            return Err(BannerError::InvalidAnimation("La animación no tiene frames".to_string()));
+        }
+        if self.repeat.is_none() && self.durations().iter().all(Duration::is_zero) {
+            return Err(BannerError::InvalidAnimation(
+                "Un bucle infinito necesita al menos un frame con duración mayor que cero".to_string(),
+            ));
+        }
+        if !self.normalize {
+            let expected = frame_size(&self.frames[0]);
+            if let Some((index, size)) = self.frames.iter().map(frame_size).enumerate().find(|(_, size)| *size != expected) {
+                return Err(BannerError::InvalidAnimation(format!(
+                    "El frame {} mide {}x{} y el primero {}x{} (usa `normalize_frames(true)` para igualarlos)",
+                    index, size.0, size.1, expected.0, expected.1
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Copia con todos los frames rellenados con espacios (y líneas vacías)
+    /// hasta el recuadro que los contiene a todos.
+    pub fn normalized(&self) -> Self {
+        let width = self.frames.iter().map(|f| content_width(f)).max().unwrap_or(0) as usize;
+        let height = self.frames.iter().map(Vec::len).max().unwrap_or(0);
+        let frames = self
+            .frames
+            .iter()
+            .map(|frame| {
+                (0..height)
+                    .map(|row| {
+                        let line = frame.get(row).map_or("", String::as_str);
+                        let fill = width.saturating_sub(get_line_width_accurate(line) as usize);
+                        format!("{}{}", line, " ".repeat(fill))
+                    })
+                    .collect()
+            })
+            .collect();
+        Self { frames, ..self.clone() }
+    }
+
+    /// Valida la animación y, si `normalize` está activo, devuelve la versión normalizada.
+    pub(crate) fn prepared(&self) -> Result<Cow<'_, Self>, BannerError> {
+        self.validate()?;
+        Ok(if self.normalize { Cow::Owned(self.normalized()) } else { Cow::Borrowed(self) })
+    }
+
+    // --- Combinadores ---
+
+    /// Duración efectiva de cada frame.
+    fn durations(&self) -> Vec<Duration> {
+        (0..self.frames.len()).map(|i| self.delay_for(i)).collect()
+    }
+
+    /// Sustituye frames y duraciones, fijando la duración de cada frame.
+    fn with_timeline(mut self, timeline: Vec<(Frame, Duration)>) -> Self {
+        let (frames, durations): (Vec<_>, Vec<_>) = timeline.into_iter().unzip();
+        self.frames = frames;
+        self.frame_durations = durations.into_iter().map(Some).collect();
+        self
+    }
+
+    /// Frames desplegados con sus repeticiones (una infinita cuenta como una
+    /// pasada) y `hold_last` sumado al último.
+    fn unrolled(&self) -> Vec<(Frame, Duration)> {
+        let pass: Vec<(Frame, Duration)> = self.frames.iter().cloned().zip(self.durations()).collect();
+        let passes = self.repeat.unwrap_or(1).max(1);
+        let mut timeline: Vec<_> = (0..passes).flat_map(|_| pass.iter().cloned()).collect();
+        if self.repeat.is_some()
+            && let Some((_, last)) = timeline.last_mut()
+        {
+            *last += self.hold_last;
+        }
+        timeline
+    }
+
+    /// Reproduce `next` a continuación. Las repeticiones finitas y `hold_last`
+    /// de cada parte se despliegan en frames; el resultado se reproduce una
+    /// vez y conserva las zonas clicables de ambas.
+    pub fn then(self, next: Animation) -> Self {
+        let mut timeline = self.unrolled();
+        timeline.extend(next.unrolled());
+        let mut links = self.links.clone();
+        links.extend(next.links.into_iter().filter(|l| !self.links.contains(l)));
+        Self { links, hold_last: Duration::ZERO, repeat: Some(1), ..self }.with_timeline(timeline)
+    }
+
+    /// Concatena varias animaciones en orden (ver `then`). `None` si no hay ninguna.
+    pub fn concat(parts: impl IntoIterator<Item = Animation>) -> Option<Self> {
+        parts.into_iter().reduce(Animation::then)
+    }
+
+    /// Invierte el orden de los frames (cada uno conserva su duración).
+    pub fn reversed(self) -> Self {
+        let mut timeline: Vec<_> = self.frames.iter().cloned().zip(self.durations()).collect();
+        timeline.reverse();
+        self.with_timeline(timeline)
+    }
+
+    /// Ida y vuelta: los frames hacia delante y luego hacia atrás, sin repetir
+    /// el último ni el primero, de modo que el bucle es continuo.
+    pub fn ping_pong(self) -> Self {
+        let forward: Vec<_> = self.frames.iter().cloned().zip(self.durations()).collect();
+        let back = forward.iter().rev().skip(1).take(forward.len().saturating_sub(2)).cloned();
+        let timeline = forward.iter().cloned().chain(back).collect();
+        self.with_timeline(timeline)
+    }
+
+    /// Cambia la velocidad: `2.0` reproduce el doble de rápido y `0.5` a la
+    /// mitad. Afecta a todas las duraciones, incluido `hold_last`; las que no
+    /// caben en un `Duration` con factores diminutos se quedan en `Duration::MAX`.
+    pub fn speed(mut self, factor: f32) -> Self {
+        if !(factor.is_finite() && factor > 0.0) {
+            return self;
+        }
+        let scale = |d: Duration| Duration::try_from_secs_f64(d.as_secs_f64() / factor as f64).unwrap_or(Duration::MAX);
+        self.frame_delay = scale(self.frame_delay);
+        self.hold_last = scale(self.hold_last);
+        for duration in self.frame_durations.iter_mut().flatten() {
+            *duration = scale(*duration);
+        }
+        self
+    }
+
+    /// Conserva solo los frames del rango indicado (recortado a los existentes).
+    pub fn trim(self, range: impl std::ops::RangeBounds<usize>) -> Self {
+        let timeline: Vec<_> = self
+            .frames
+            .iter()
+            .cloned()
+            .zip(self.durations())
+            .enumerate()
+            .filter(|(i, _)| range.contains(i))
+            .map(|(_, entry)| entry)
+            .collect();
+        self.with_timeline(timeline)
+    }
+
+    /// Superpone `top` frame a frame: sus caracteres que no son espacios tapan
+    /// los de esta animación. Si una es más corta mantiene su último frame; las
+    /// duraciones son las de esta animación mientras tenga frames. Pensado
+    /// para frames de texto plano (sin secuencias de escape).
+    pub fn overlay(self, top: Animation) -> Self {
+        let (base_durations, top_durations) = (self.durations(), top.durations());
+        let len = self.frames.len().max(top.frames.len());
+        let timeline = (0..len)
+            .map(|i| {
+                let base = self.frames.get(i).or(self.frames.last());
+                let over = top.frames.get(i).or(top.frames.last());
+                let frame = match (base, over) {
+                    (Some(base), Some(over)) => overlay_frame(base, over),
+                    (base, over) => base.or(over).cloned().unwrap_or_default(),
+                };
+                let duration = base_durations.get(i).or(top_durations.get(i)).copied().unwrap_or(self.frame_delay);
+                (frame, duration)
+            })
+            .collect();
+        self.with_timeline(timeline)
+    }
+}
+
+/// Ancho (columnas visibles de la línea más larga) y alto de un frame.
+fn frame_size(frame: &Frame) -> (u16, usize) {
+    (content_width(frame), frame.len())
+}
+
+/// Combina dos frames carácter a carácter; los espacios de `top` son transparentes.
+fn overlay_frame(base: &Frame, top: &Frame) -> Frame {
+    (0..base.len().max(top.len()))
+        .map(|row| {
+            let mut chars: Vec<char> = base.get(row).map(|l| l.chars().collect()).unwrap_or_default();
+            for (x, ch) in top.get(row).map(|l| l.chars()).into_iter().flatten().enumerate() {
+                if ch == ' ' { continue; }
+                if chars.len() <= x {
+                    chars.resize(x + 1, ' ');
+                }
+                chars[x] = ch;
+            }
+            chars.into_iter().collect()
+        })
+        .collect()
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_per_frame_durations_fall_back_to_frame_delay() {
+        let mut animation = Animation::new(vec![vec!["a".into()], vec!["b".into()]], 100)
+            .with_frame_duration(1, Duration::from_millis(500));
+        animation.push_frame(vec!["c".into()], None);
+        assert_eq!(animation.delay_for(0), Duration::from_millis(100));
+        assert_eq!(animation.delay_for(1), Duration::from_millis(500));
+        assert_eq!(animation.delay_for(2), Duration::from_millis(100));
+        assert_eq!(animation.frame_durations.len(), animation.frames.len());
+    }
+
+    fn frames(names: &str) -> Animation {
+        Animation::new(names.chars().map(|c| vec![c.to_string()]).collect(), 100)
+    }
+
+    fn names(animation: &Animation) -> String {
+        animation.frames.iter().map(|f| f[0].as_str()).collect()
+    }
+
+    #[test]
+    fn test_sequence_combinators() {
+        let intro = frames("ab").repeat(Some(2)).hold_last(Duration::from_millis(50));
+        let joined = intro.then(frames("c").delay_ms(300));
+        assert_eq!(names(&joined), "ababc");
+        assert_eq!(joined.delay_for(3), Duration::from_millis(150));
+        assert_eq!(joined.delay_for(4), Duration::from_millis(300));
+
+        assert_eq!(names(&frames("abcd").ping_pong()), "abcdcb");
+        assert_eq!(names(&frames("abc").reversed()), "cba");
+        assert_eq!(names(&frames("abcde").trim(1..3)), "bc");
+        assert_eq!(frames("ab").speed(2.0).delay_for(1), Duration::from_millis(50));
+        assert_eq!(frames("ab").speed(f32::MIN_POSITIVE).delay_for(0), Duration::MAX);
+    }
+
+    #[test]
+    fn test_overlay_keeps_base_where_top_is_blank() {
+        let base = Animation::new(vec![vec!["....".into()], vec!["....".into()]], 100);
+        let top = Animation::new(vec![vec![" x".into()]], 100);
+        let merged = base.overlay(top);
+        assert_eq!(merged.frames, vec![vec![".x..".to_string()], vec![".x..".to_string()]]);
+    }
+
+    #[test]
+    fn test_validation_and_normalization() {
+        assert!(matches!(frames("").validate(), Err(BannerError::InvalidAnimation(_))));
+        assert!(frames("ab").delay_ms(0).repeat(None).validate().is_err());
+        assert!(frames("ab").delay_ms(0).validate().is_ok());
+
+        let uneven = Animation::new(vec![vec!["abc".into()], vec!["a".into(), "bc".into()]], 100);
+        assert!(uneven.validate().is_err());
+        let uneven = uneven.normalize_frames(true);
+        assert!(uneven.validate().is_ok());
+        let prepared = uneven.prepared().unwrap();
+        assert_eq!(prepared.frames, vec![
+            vec!["abc".to_string(), "   ".to_string()],
+            vec!["a  ".to_string(), "bc ".to_string()],
+        ]);
+    }
+}
@@ -0,0 +1,217 @@
+// --- src/asciicast.rs ---
+//! Soporte para grabaciones de asciinema (formato asciicast v2): la salida
+//! grabada se reproduce sobre una pantalla virtual y se muestrea en frames, y
+//! las animaciones se exportan con la misma salida que produce el renderizador.
+
+use crate::animation::Animation;
+use crate::error::BannerError;
+use crate::grid::{Grid, Screen};
+use crate::export::{compose, plain_options};
+use crate::renderer::RenderOptions;
+use crate::style::Style;
+use serde_json::Value;
+use std::io::{BufRead, Write};
+use std::path::Path;
+use std::time::Duration;
+
+/// Intervalo mínimo entre frames: los eventos más próximos se agrupan en un
+/// único frame (unos 30 fps).
+const MIN_FRAME_INTERVAL: f64 = 1.0 / 30.0;
+/// Ancho y alto máximos aceptados en la cabecera, para que un archivo
+/// malicioso no reserve una pantalla virtual gigantesca.
+const MAX_DIMENSION: u16 = 1000;
+
+fn cast_error(line: usize, message: impl std::fmt::Display) -> BannerError {
+    BannerError::InvalidAnimation(format!("asciicast, línea {}: {}", line, message))
+}
+
+impl Animation {
+    /// Carga una grabación `.cast` (asciicast v2).
+    pub fn from_asciicast_file(path: impl AsRef<Path>) -> Result<Self, BannerError> {
+        Self::from_asciicast(std::io::BufReader::new(std::fs::File::open(path)?))
+    }
+
+    /// Reproduce una grabación asciicast v2 sobre una pantalla virtual del
+    /// tamaño indicado en la cabecera y genera un frame por cada cambio visible.
+    ///
+    /// Cada frame dura hasta el siguiente evento de salida (respetando
+    /// `idle_time_limit` si la cabecera lo define); el último usa `frame_delay`.
+    pub fn from_asciicast(reader: impl BufRead) -> Result<Self, BannerError> {
+        let mut lines = reader.lines().enumerate();
+        let header: Value = match lines.next() {
+            Some((_, line)) => serde_json::from_str(&line?).map_err(|e| cast_error(1, e))?,
+            None => return Err(cast_error(1, "archivo vacío")),
+        };
+        if header["version"].as_u64() != Some(2) {
+            return Err(cast_error(1, "solo se admite la versión 2"));
+        }
+        let dimension = |key: &str| {
+            let value = header[key].as_u64().filter(|&n| n > 0)
+                .ok_or_else(|| cast_error(1, format!("'{}' ausente o inválido", key)))?;
+            u16::try_from(value).ok().filter(|&n| n <= MAX_DIMENSION)
+                .ok_or_else(|| cast_error(1, format!("'{}' supera el máximo de {}", key, MAX_DIMENSION)))
+        };
+        let mut screen = Screen::new(dimension("width")?, dimension("height")?);
+        let idle_limit = header["idle_time_limit"].as_f64().filter(|l| *l > 0.0);
+
+        // La pantalla virtual omite los blancos finales: los frames se igualan al mostrarse.
+        let mut animation = Animation::new(Vec::new(), 100).normalize_frames(true);
+        // Reloj con las pausas largas ya recortadas, y arranque del frame pendiente.
+        let (mut clock, mut last_event) = (0.0_f64, 0.0_f64);
+        let mut frame_start: Option<f64> = None;
+        let mut snapshots: Vec<(f64, Vec<String>)> = Vec::new();
+
+        for (index, line) in lines {
+            let line = line?;
+            if line.trim().is_empty() { continue; }
+            let event: Value = serde_json::from_str(&line).map_err(|e| cast_error(index + 1, e))?;
+            let (Some(time), Some(kind), Some(data)) = (event[0].as_f64(), event[1].as_str(), event[2].as_str()) else {
+                return Err(cast_error(index + 1, "evento con formato inválido"));
+            };
+            let mut delta = (time - last_event).max(0.0);
+            if let Some(limit) = idle_limit { delta = delta.min(limit); }
+            clock += delta;
+            last_event = time;
+            // Las duraciones de los frames nunca superan el reloj: si este no cabe
+            // en un `Duration`, la grabación no es válida.
+            Duration::try_from_secs_f64(clock).map_err(|_| cast_error(index + 1, "marca de tiempo fuera de rango"))?;
+            if kind != "o" { continue; }
+
+            match frame_start {
+                Some(start) if clock - start >= MIN_FRAME_INTERVAL => {
+                    snapshots.push((start, screen.lines()));
+                    frame_start = Some(clock);
+                }
+                None => frame_start = Some(clock),
+                _ => {}
+            }
+            screen.feed(data);
+        }
+        if let Some(start) = frame_start {
+            snapshots.push((start, screen.lines()));
+        }
+
+        // Los frames idénticos consecutivos se fusionan alargando el anterior.
+        snapshots.dedup_by(|next, previous| next.1 == previous.1);
+        let ends: Vec<Option<f64>> = snapshots.iter().skip(1).map(|(t, _)| Some(*t)).chain([None]).collect();
+        for ((start, frame), end) in snapshots.into_iter().zip(ends) {
+            animation.push_frame(frame, end.map(|end| Duration::from_secs_f64(end - start)));
+        }
+        if animation.frames.is_empty() {
+            return Err(BannerError::InvalidAnimation("La grabación no contiene salida".to_string()));
+        }
+        Ok(animation)
+    }
+}
+
+/// Escribe `animation` como grabación asciicast v2, tal y como se vería con
+/// `options`. El tamaño de la grabación es el del contenido decorado (más el
+/// relleno superior); una animación infinita se exporta con una sola pasada.
+pub(crate) fn write_asciicast(
+    animation: &Animation,
+    options: &RenderOptions,
+    mut writer: impl Write,
+) -> Result<(), BannerError> {
+    let composition = compose(animation, options, animation.repeat.unwrap_or(1).max(1))?;
+    let (width, height) = (composition.width, composition.height);
+    writeln!(
+        writer,
+        "{{\"version\": 2, \"width\": {}, \"height\": {}, \"env\": {{\"TERM\": \"xterm-256color\"}}}}",
+        width, height
+    )?;
+
+    let mut event = |time: Duration, data: &str| -> Result<(), BannerError> {
+        writeln!(writer, "[{:.6}, \"o\", {}]", time.as_secs_f64(), Value::from(data))?;
+        Ok(())
+    };
+    let mut previous = Grid::new(width, height);
+    let mut time = Duration::ZERO;
+    // Primer evento: cursor oculto y pantalla limpia, como en la pantalla alternativa.
+    let mut pending = String::from("\x1b[?25l\x1b[H\x1b[2J");
+    for (grid, duration) in composition.frames {
+        pending.push_str(&grid.diff(&previous, 0));
+        if !pending.is_empty() {
+            event(time, &pending)?;
+            pending.clear();
+        }
+        previous = grid;
+        time += duration;
+    }
+    // El último evento marca el final: sin él los reproductores cortarían el último frame.
+    event(time, &format!("\x1b[{};1H\x1b[?25h", height))?;
+    writer.flush()?;
+    Ok(())
+}
+
+impl Animation {
+    /// Exporta la animación como grabación asciicast v2 (reproducible con
+    /// `asciinema play` o el reproductor web), respetando `frame_delay`, las
+    /// duraciones por frame, `repeat` y `hold_last`. Los frames se exportan
+    /// alineados a la izquierda y sin estilo; para incluir estilos, bordes o
+    /// alineación usa `Banner::to_asciicast`.
+    pub fn to_asciicast(&self, writer: impl Write) -> Result<(), BannerError> {
+        let style = Style::default();
+        write_asciicast(self, &plain_options(&style), writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_replay_cast_into_frames() {
+        let cast = concat!(
+            "{\"version\": 2, \"width\": 8, \"height\": 2, \"idle_time_limit\": 1.0}\n",
+            "[0.0, \"o\", \"ab\"]\n",
+            "[0.01, \"o\", \"c\"]\n",
+            "[0.5, \"i\", \"x\"]\n",
+            "[5.0, \"o\", \"\\r\\n\\u001b[32mok\"]\n",
+            "[5.2, \"o\", \"\\u001b[1;1H\\u001b[K\"]\n",
+        );
+        let animation = Animation::from_asciicast(cast.as_bytes()).unwrap();
+        assert_eq!(animation.frames.len(), 3);
+        assert_eq!(animation.frames[0], vec!["abc".to_string(), String::new()]);
+        assert_eq!(animation.frames[1][1], "\x1b[0;32mok\x1b[0m");
+        // 0.49 s hasta la entrada y la pausa de 4.5 s recortada a `idle_time_limit`.
+        assert!((animation.delay_for(0).as_secs_f64() - 1.5).abs() < 1e-6);
+        assert_eq!(animation.frames[2][0], "");
+    }
+
+    #[test]
+    fn test_rejects_other_versions() {
+        let err = Animation::from_asciicast("{\"version\": 1}".as_bytes()).unwrap_err();
+        assert!(err.to_string().contains("versión 2"));
+    }
+
+    #[test]
+    fn test_rejects_huge_timestamps() {
+        let cast = "{\"version\": 2, \"width\": 4, \"height\": 1}\n[0.0, \"o\", \"a\"]\n[1e300, \"o\", \"b\"]";
+        let err = Animation::from_asciicast(cast.as_bytes()).unwrap_err();
+        assert!(err.to_string().contains("línea 3"));
+    }
+
+    #[test]
+    fn test_rejects_huge_screens() {
+        let cast = "{\"version\": 2, \"width\": 65535, \"height\": 65535}";
+        let err = Animation::from_asciicast(cast.as_bytes()).unwrap_err();
+        assert!(err.to_string().contains("línea 1") && err.to_string().contains("máximo"));
+    }
+
+    #[test]
+    fn test_export_round_trips_through_the_reader() {
+        let frames = vec![vec!["ab".to_string(), "c".to_string()], vec!["ab".to_string(), "d".to_string()]];
+        let animation = Animation::new(frames.clone(), 250).repeat(Some(2)).hold_last(Duration::from_secs(1));
+        let mut cast = Vec::new();
+        animation.to_asciicast(&mut cast).unwrap();
+
+        let text = String::from_utf8(cast.clone()).unwrap();
+        let header: Value = serde_json::from_str(text.lines().next().unwrap()).unwrap();
+        assert_eq!((header["width"].as_u64(), header["height"].as_u64()), (Some(2), Some(2)));
+        assert!(text.lines().last().unwrap().starts_with("[2.000000,"));
+
+        let replayed = Animation::from_asciicast(cast.as_slice()).unwrap();
+        assert_eq!(replayed.frames, [frames.clone(), frames].concat());
+        assert_eq!(replayed.delay_for(0), Duration::from_millis(250));
+    }
+}
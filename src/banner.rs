@@ -1,8 +1,15 @@
 // --- src/banner.rs ---
 use crate::animation::Animation;
+use crate::effects::Reveal;
 use crate::error::BannerError;
+use crate::handle::{AnimationHandle, PlaybackControl};
 use crate::fonts::{load_font, BuiltinFont, FontSource};
-use crate::style::Style;
+use crate::layout::{Alignment, BackgroundMode, BorderStyle};
+use crate::overflow::Overflow;
+use crate::renderer::{FallbackFrame, RenderOptions, ScreenMode};
+use crate::style::{ColorMode, Style};
+use crate::theme::Theme;
+use figlet_rs::FIGfont;
 // Quitamos FIGureOptions y HorizontalLayout de la importación
 use std::path::Path;
 
@@ -26,8 +33,17 @@ pub struct FigletOptions {
 pub struct Banner {
     source: BannerSource,
     style: Style,
-    centered: bool,
+    alignment: Alignment,
+    border: Option<BorderStyle>,
     padding_top: u16,
+    hyperlink: Option<String>,
+    color_mode: ColorMode,
+    background_mode: BackgroundMode,
+    /// `None` hasta que se elige con `screen_mode`: ver `resolved_screen_mode`.
+    screen_mode: Option<ScreenMode>,
+    fallback_width: u16,
+    fallback_frame: FallbackFrame,
+    overflow: Overflow,
 }
 
 /// Contenido interno del banner.
@@ -39,6 +55,8 @@ enum BannerSource {
         options: FigletOptions, // Mantenemos por si futuras versiones lo usan o para info
         /// Contenido generado cacheado (solo Vec<String>).
         generated_cache: Option<BannerContent>, // <-- Cambio: Solo BannerContent
+        /// Efecto de aparición opcional: si existe, el texto se muestra animado.
+        reveal: Option<Reveal>,
     },
     AnimatedFrames {
         animation: Animation,
@@ -54,10 +72,19 @@ impl Banner {
                 font_source: FontSource::default(),
                 options: FigletOptions::default(),
                 generated_cache: None, // Cache vacío inicialmente
+                reveal: None,
             },
             style: Style::default(),
-            centered: true,
+            alignment: Alignment::Center,
+            border: None,
             padding_top: 1,
+            hyperlink: None,
+            color_mode: ColorMode::Auto,
+            background_mode: BackgroundMode::Glyphs,
+            screen_mode: None,
+            fallback_width: 80,
+            fallback_frame: FallbackFrame::default(),
+            overflow: Overflow::default(),
         }
     }
 
@@ -66,8 +93,16 @@ impl Banner {
         Self {
             source: BannerSource::AnimatedFrames { animation },
             style: Style::default(),
-            centered: true,
+            alignment: Alignment::Center,
+            border: None,
             padding_top: 1,
+            hyperlink: None,
+            color_mode: ColorMode::Auto,
+            background_mode: BackgroundMode::Glyphs,
+            screen_mode: None,
+            fallback_width: 80,
+            fallback_frame: FallbackFrame::default(),
+            overflow: Overflow::default(),
         }
     }
 
@@ -102,6 +137,15 @@ impl Banner {
         self
     }
 
+    /// Muestra el texto FIGlet con un efecto de aparición procedural
+    /// (máquina de escribir, deslizamiento, cortina...). Sin efecto en banners animados.
+    pub fn with_reveal(mut self, effect: Reveal) -> Self {
+        if let BannerSource::FigletText { reveal, .. } = &mut self.source {
+            *reveal = Some(effect);
+        }
+        self
+    }
+
     /// Establece el estilo general.
     pub fn with_style(mut self, style: Style) -> Self {
         self.style = style;
@@ -123,8 +167,73 @@ impl Banner {
     pub fn underline(mut self) -> Self {
         self.style = self.style.underline(); self
     }
+    /// Activa cualquier atributo SGR (atenuado, parpadeo, tachado, subrayado ondulado...).
+    pub fn with_attribute(mut self, attribute: crate::style::Attribute) -> Self {
+        self.style = self.style.attribute(attribute); self
+    }
     pub fn centered(mut self, centered: bool) -> Self {
-        self.centered = centered; self
+        self.alignment = if centered { Alignment::Center } else { Alignment::Left }; self
+    }
+    pub fn align(mut self, alignment: Alignment) -> Self {
+        self.alignment = alignment; self
+    }
+    /// Enmarca el banner con el borde indicado.
+    pub fn with_border(mut self, border: BorderStyle) -> Self {
+        self.border = Some(border); self
+    }
+
+    /// Convierte el banner en un hipervínculo (OSC 8) hacia `url`, sin sus
+    /// caracteres de control. Se omite cuando la política de escapes
+    /// (`color_mode`) está desactivada.
+    pub fn hyperlink(mut self, url: &str) -> Self {
+        self.hyperlink = Some(crate::hyperlink::sanitize_url(url)); self
+    }
+    /// Define cómo se pinta el fondo: por línea, como panel rectangular o transparente.
+    pub fn background_mode(mut self, mode: BackgroundMode) -> Self {
+        self.background_mode = mode; self
+    }
+    /// Elige dónde se dibujan las animaciones: pantalla alternativa (por defecto)
+    /// o en línea, dejando el último frame visible en el historial. Los efectos
+    /// de aparición (`with_reveal`) se muestran en línea salvo que se indique otra cosa.
+    pub fn screen_mode(mut self, mode: ScreenMode) -> Self {
+        self.screen_mode = Some(mode); self
+    }
+    /// Columnas que se suponen para alinear el banner cuando no se puede
+    /// consultar el tamaño de la terminal (salida redirigida). Por defecto 80.
+    pub fn fallback_width(mut self, columns: u16) -> Self {
+        self.fallback_width = columns; self
+    }
+    /// Frame que se imprime en lugar de la animación cuando la salida no es
+    /// una terminal. Por defecto, el último.
+    pub fn fallback_frame(mut self, frame: FallbackFrame) -> Self {
+        self.fallback_frame = frame; self
+    }
+    /// Qué hacer si el texto FIGlet no cabe en la terminal: nada (por defecto,
+    /// la terminal parte las líneas), recortarlo con `…`, repartirlo en varias
+    /// filas, estrecharlo o mostrarlo en texto plano.
+    /// Si la salida no es una terminal el banner se imprime completo.
+    pub fn overflow(mut self, overflow: Overflow) -> Self {
+        self.overflow = overflow; self
+    }
+    /// Establece la política de emisión de colores y secuencias de escape.
+    pub fn color_mode(mut self, mode: ColorMode) -> Self {
+        self.color_mode = mode; self
+    }
+
+    /// Aplica un tema: estilo completo y, si el tema los define, fuente, borde y alineación.
+    /// La fuente solo afecta a banners FIGlet.
+    pub fn with_theme(mut self, theme: &Theme) -> Self {
+        self.style = theme.style();
+        if let Some(font) = &theme.font {
+            self = self.with_font(font.clone());
+        }
+        if theme.border.is_some() {
+            self.border = theme.border;
+        }
+        if let Some(alignment) = theme.alignment {
+            self.alignment = alignment;
+        }
+        self
     }
     pub fn padding_top(mut self, lines: u16) -> Self {
         self.padding_top = lines; self
@@ -133,56 +242,188 @@ impl Banner {
     /// Genera el contenido FIGlet si es necesario y lo cachea (solo `Vec<String>`).
     /// **Modificado:** Ya no devuelve referencias. Solo asegura que la caché esté poblada.
     fn ensure_generated_content(&mut self) -> Result<(), BannerError> {
-        if let BannerSource::FigletText { text, font_source, /* options (no usado) */ generated_cache, .. } = &mut self.source {
-            if generated_cache.is_none() {
-                let fig_font = load_font(font_source)?;
-
-                // --- CAMBIO: Usar convert() ---
-                let figure = fig_font.convert(text)
-                    .ok_or_else(|| BannerError::FigletGeneration(format!("No se pudo convertir texto FIGlet: '{}'", text)))?;
+        if let BannerSource::FigletText { text, font_source, /* options (no usado) */ generated_cache, .. } = &mut self.source
+            && generated_cache.is_none()
+        {
+            let fig_font = load_font(font_source)?;
+            let content_vec = figlet_lines(&fig_font, text)?;
 
-                let content_vec = figure.to_string().lines().map(String::from).collect();
-
-                // --- CAMBIO: Cachear solo Vec<String> ---
-                *generated_cache = Some(content_vec);
-            }
+            // --- CAMBIO: Cachear solo Vec<String> ---
+            *generated_cache = Some(content_vec);
         }
         Ok(())
     }
 
 
+    /// Contenido FIGlet (ya generado) ajustado al ancho disponible según `overflow`.
+    fn fitted_content(&self, options: &RenderOptions) -> Result<BannerContent, BannerError> {
+        let BannerSource::FigletText { text, font_source, generated_cache: Some(content), .. } = &self.source else {
+            return Err(BannerError::Internal("Contenido generado FIGlet inesperadamente ausente después de ensure".to_string()));
+        };
+        let width = crate::renderer::available_width(options);
+        let (terminal, unicode) =
+            crate::capabilities::TerminalCapabilities::with_current(|caps| (caps.is_tty, caps.unicode));
+        // La fuente solo se vuelve a cargar si hay que repartir el texto en filas.
+        let font = once_cell::unsync::OnceCell::new();
+        crate::overflow::fit(content, text, width, self.overflow, terminal, unicode, |fragment| {
+            figlet_lines(font.get_or_try_init(|| load_font(font_source))?, fragment)
+        })
+    }
+
+    /// Modo de pantalla efectivo: el elegido o, si no, en línea para los
+    /// efectos de aparición (el banner terminado debe quedar a la vista) y
+    /// pantalla alternativa para el resto.
+    fn resolved_screen_mode(&self) -> ScreenMode {
+        self.screen_mode.unwrap_or(match &self.source {
+            BannerSource::FigletText { reveal: Some(_), .. } => ScreenMode::Inline,
+            _ => ScreenMode::AlternateScreen,
+        })
+    }
+
+    /// Opciones de presentación que se pasan al renderizador.
+    fn render_options(&self) -> RenderOptions<'_> {
+        RenderOptions {
+            style: &self.style,
+            alignment: self.alignment,
+            padding_top: self.padding_top,
+            border: self.border,
+            hyperlink: self.hyperlink.as_deref(),
+            color_mode: self.color_mode,
+            background_mode: self.background_mode,
+            screen_mode: self.resolved_screen_mode(),
+            fallback_width: self.fallback_width,
+            fallback_frame: self.fallback_frame,
+            hyperlinks: true,
+        }
+    }
+
     /// Muestra el banner en la terminal.
     pub fn display(&mut self) -> Result<(), BannerError> {
-        match &mut self.source {
+        self.display_controlled(&PlaybackControl::default())
+    }
+
+    /// Muestra el banner en un hilo aparte y devuelve un handle para pausar,
+    /// reanudar o detener la animación sin bloquear al llamador.
+    pub fn display_in_background(self) -> AnimationHandle {
+        AnimationHandle::spawn(self)
+    }
+
+    /// Muestra el banner sin bloquear el runtime de tokio (feature `async`).
+    ///
+    /// Las esperas entre frames usan temporizadores asíncronos y el teclado se
+    /// lee con el stream de eventos de crossterm. Soltar el future cancela la
+    /// animación y restaura la terminal igual que al terminar normalmente.
+    #[cfg(feature = "async")]
+    pub async fn display_async(&mut self) -> Result<(), BannerError> {
+        self.ensure_generated_content()?;
+        let options = self.render_options();
+        match &self.source {
+            BannerSource::FigletText { reveal, .. } => {
+                let content = self.fitted_content(&options)?;
+                match reveal {
+                    Some(reveal) => crate::renderer::display_animated_async(&reveal.animation(&content), &options).await,
+                    None => crate::renderer::display_static_content(&content, &options),
+                }
+            }
+            BannerSource::AnimatedFrames { animation } => {
+                crate::renderer::display_animated_async(animation, &options).await
+            }
+        }
+    }
+
+    /// Exporta el banner como grabación asciicast v2 con el mismo estilo,
+    /// borde y alineación que tendría en pantalla. Un banner estático produce
+    /// una grabación de un solo frame; uno con efecto de aparición o animado
+    /// conserva la duración de cada frame.
+    #[cfg(feature = "asciicast")]
+    pub fn to_asciicast(&mut self, writer: impl std::io::Write) -> Result<(), BannerError> {
+        let animation = self.export_animation()?;
+        crate::asciicast::write_asciicast(&animation, &self.render_options(), writer)
+    }
+
+    /// Exporta el banner como documento SVG (texto monoespaciado con colores,
+    /// atributos y fondo). Los banners animados o con efecto de aparición se
+    /// exportan como SVG animado mediante keyframes CSS.
+    pub fn to_svg(&mut self, writer: impl std::io::Write) -> Result<(), BannerError> {
+        let animation = self.export_animation()?;
+        crate::export::svg::write_animation(&animation, &self.render_options(), writer)
+    }
+
+    /// Exporta el banner como HTML: un bloque `<pre>` con un `<span>` por tramo
+    /// de color (estilos en línea o clases CSS, según `options`). Los banners
+    /// animados o con efecto de aparición se exportan con su último frame.
+    pub fn to_html(&mut self, options: &crate::export::html::HtmlOptions, writer: impl std::io::Write) -> Result<(), BannerError> {
+        let mut animation = self.export_animation()?;
+        animation.frames.drain(..animation.frames.len().saturating_sub(1));
+        let composition = crate::export::compose(&animation, &self.render_options(), 1)?;
+        let (grid, _) = &composition.frames[0];
+        crate::export::html::write_html(grid, options, writer)
+    }
+
+    /// Rasteriza el banner a PNG con la fuente de mapa de bits incrustada
+    /// (feature `raster`). Los banners animados se exportan con su último frame.
+    #[cfg(feature = "raster")]
+    pub fn to_png(&mut self, options: &crate::export::raster::RasterOptions, writer: impl std::io::Write) -> Result<(), BannerError> {
+        let mut animation = self.export_animation()?;
+        animation.frames.drain(..animation.frames.len().saturating_sub(1));
+        let composition = crate::export::compose(&animation, &self.render_options(), 1)?;
+        crate::export::raster::write_png(&composition.frames[0].0, options, writer)
+    }
+
+    /// Rasteriza el banner a GIF animado, con los retardos de cada frame
+    /// (feature `raster`). Un banner estático produce un GIF de un solo frame.
+    #[cfg(feature = "raster")]
+    pub fn to_gif(&mut self, options: &crate::export::raster::RasterOptions, writer: impl std::io::Write) -> Result<(), BannerError> {
+        let animation = self.export_animation()?;
+        crate::export::raster::write_animation_gif(&animation, &self.render_options(), options, writer)
+    }
+
+    /// Contenido del banner como animación para los exportadores: un único
+    /// frame si es estático.
+    fn export_animation(&mut self) -> Result<Animation, BannerError> {
+        self.ensure_generated_content()?;
+        match &self.source {
+            BannerSource::FigletText { generated_cache: Some(content_vec), reveal: Some(reveal), .. } => Ok(reveal.animation(content_vec)),
+            BannerSource::FigletText { generated_cache: Some(content_vec), .. } => Ok(Animation::new(vec![content_vec.clone()], 0)),
             BannerSource::FigletText { .. } => {
-                // --- CAMBIO: Separar préstamo mutable de inmutables ---
-                // 1. Asegurar contenido generado (préstamo mutable termina aquí)
-                self.ensure_generated_content()?;
+                Err(BannerError::Internal("Contenido generado FIGlet inesperadamente ausente después de ensure".to_string()))
+            }
+            BannerSource::AnimatedFrames { animation } => Ok(animation.clone()),
+        }
+    }
 
-                // 2. Obtener referencias inmutables para renderizar
-                //    (volvemos a hacer match, ahora sobre &self implícitamente)
-                if let BannerSource::FigletText { generated_cache: Some(content_vec), .. } = &self.source {
-                     crate::renderer::display_static_content(
-                         content_vec,       // Préstamo inmutable del cache
-                         &self.style,       // Préstamo inmutable
-                         self.centered,     // Copia (bool es Copy)
-                         self.padding_top,  // Copia (u16 es Copy)
-                     )
-                } else {
-                     // Esto solo ocurriría si ensure_generated_content falla lógicamente
-                     Err(BannerError::Internal("Contenido generado FIGlet inesperadamente ausente después de ensure".to_string()))
+    /// Muestra el banner atendiendo a un control de reproducción externo.
+    pub(crate) fn display_controlled(&mut self, control: &PlaybackControl) -> Result<(), BannerError> {
+        match &self.source {
+            BannerSource::FigletText { .. } => {
+                // Primero se genera (y cachea) el FIGlet; luego se ajusta al ancho disponible.
+                self.ensure_generated_content()?;
+                let options = self.render_options();
+                let content = self.fitted_content(&options)?;
+                match &self.source {
+                    BannerSource::FigletText { reveal: Some(reveal), .. } => {
+                        crate::renderer::display_animated(&reveal.animation(&content), &options, control)
+                    }
+                    _ => crate::renderer::display_static_content(&content, &options),
                 }
             }
             BannerSource::AnimatedFrames { animation } => {
                  // Para la animación, podemos tomar prestados los campos necesarios
                  // animation es Clone, así que podríamos clonarlo si fuera necesario, pero no hace falta
                  crate::renderer::display_animated(
-                     animation,         // Préstamo inmutable
-                     &self.style,       // Préstamo inmutable
-                     self.centered,     // Copia
-                     self.padding_top,  // Copia
+                     animation,               // Préstamo inmutable
+                     &self.render_options(),  // Préstamo inmutable
+                     control,                 // Pausa/parada desde un AnimationHandle
                  )
             }
         }
     }
-}
\ No newline at end of file
+}
+
+/// Genera el arte FIGlet de `text` como líneas.
+pub(crate) fn figlet_lines(font: &FIGfont, text: &str) -> Result<BannerContent, BannerError> {
+    let figure = font
+        .convert(text)
+        .ok_or_else(|| BannerError::FigletGeneration(format!("No se pudo convertir texto FIGlet: '{}'", text)))?;
+    Ok(figure.to_string().lines().map(String::from).collect())
+}
@@ -0,0 +1,497 @@
+// --- src/capabilities.rs ---
+//! Detección de las capacidades de la terminal: profundidad de color, Unicode,
+//! salida sincronizada, hipervínculos, protocolos de imagen y pantalla
+//! alternativa. Se decide con variables de entorno y terminfo, y opcionalmente
+//! preguntando a la terminal (DA1 y XTVERSION).
+
+use once_cell::sync::Lazy;
+use std::io::{IsTerminal, Write};
+use std::path::PathBuf;
+use std::sync::RwLock;
+use std::time::{Duration, Instant};
+
+/// Cuántos colores puede mostrar la terminal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ColorDepth {
+    /// Sin color (p. ej. `TERM=dumb`): no se emiten colores en `ColorMode::Auto`.
+    Monochrome,
+    Ansi16,
+    Ansi256,
+    /// Color de 24 bits (`38;2;r;g;b`).
+    TrueColor,
+}
+
+/// Protocolo para mostrar imágenes en la terminal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageProtocol {
+    Sixel,
+    Kitty,
+    Iterm2,
+}
+
+/// Lo que la terminal sabe hacer. El renderizador consulta las capacidades
+/// actuales (`TerminalCapabilities::current()`); en tests, o si la detección
+/// se equivoca, se pueden fijar con `set_override`.
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use vornix_banner::TerminalCapabilities;
+///
+/// // Afinar la detección preguntando a la terminal, como mucho 100 ms.
+/// let caps = TerminalCapabilities::detect().probe(Duration::from_millis(100));
+/// TerminalCapabilities::set_override(Some(caps));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TerminalCapabilities {
+    /// La salida estándar es una terminal (y no una tubería o un archivo).
+    pub is_tty: bool,
+    pub color_depth: ColorDepth,
+    /// La terminal muestra caracteres Unicode (bordes de caja, bloques...).
+    pub unicode: bool,
+    /// Salida sincronizada (`CSI ? 2026 h/l`), que evita el parpadeo entre frames.
+    pub synchronized_output: bool,
+    /// Hipervínculos OSC 8.
+    pub hyperlinks: bool,
+    /// Subrayado doble/ondulado (`4:2`, `4:3`) y color de subrayado (SGR 58).
+    pub extended_underline: bool,
+    /// Pantalla alternativa (`smcup`/`rmcup`).
+    pub alternate_screen: bool,
+    pub image_protocol: Option<ImageProtocol>,
+    /// Nombre y versión que devuelve XTVERSION (solo tras `probe`).
+    pub terminal_name: Option<String>,
+}
+
+/// Capacidades detectadas una sola vez, al primer uso.
+static DETECTED: Lazy<TerminalCapabilities> = Lazy::new(TerminalCapabilities::detect);
+/// Capacidades fijadas por el usuario, con prioridad sobre las detectadas.
+static OVERRIDE: RwLock<Option<TerminalCapabilities>> = RwLock::new(None);
+
+/// Terminales que soportan color de 24 bits, salida sincronizada y subrayados extendidos.
+const MODERN_TERMS: &[&str] = &["kitty", "wezterm", "foot", "alacritty", "contour", "ghostty"];
+
+impl TerminalCapabilities {
+    /// Detecta las capacidades con las variables de entorno y la base de datos
+    /// terminfo, sin escribir nada en la terminal.
+    pub fn detect() -> Self {
+        let env = |name: &str| std::env::var(name).ok().filter(|v| !v.is_empty());
+        let terminfo = env("TERM").and_then(|term| Terminfo::load(&term, &env));
+        Self::from_env(&env, std::io::stdout().is_terminal(), terminfo.as_ref())
+    }
+
+    /// Capacidades que usa el renderizador: las fijadas con `set_override` o,
+    /// si no hay, las detectadas al primer uso.
+    pub fn current() -> Self {
+        Self::with_current(Clone::clone)
+    }
+
+    /// Fija las capacidades que usará el renderizador (`None` vuelve a las detectadas).
+    pub fn set_override(capabilities: Option<TerminalCapabilities>) {
+        *OVERRIDE.write().unwrap_or_else(|e| e.into_inner()) = capabilities;
+    }
+
+    /// Consulta las capacidades actuales sin clonarlas.
+    pub(crate) fn with_current<R>(f: impl FnOnce(&TerminalCapabilities) -> R) -> R {
+        let overridden = OVERRIDE.read().unwrap_or_else(|e| e.into_inner());
+        f(overridden.as_ref().unwrap_or(&DETECTED))
+    }
+
+    /// Afina la detección preguntando a la terminal (XTVERSION y DA1) y
+    /// esperando la respuesta como mucho `timeout`. No hace nada si la salida
+    /// o la entrada estándar no son una terminal: con la entrada redirigida la
+    /// respuesta no llegaría por ella y se consumirían datos de la aplicación.
+    ///
+    /// La respuesta se lee de la entrada estándar y la lectura termina al
+    /// llegar la respuesta a DA1 o al agotar `timeout`; lo que la terminal
+    /// conteste después llega a la aplicación como entrada normal. Solo se
+    /// pregunta en Unix.
+    pub fn probe(mut self, timeout: Duration) -> Self {
+        if self.is_tty
+            && std::io::stdin().is_terminal()
+            && let Some(reply) = query_terminal(timeout)
+        {
+            self.apply_replies(&reply);
+        }
+        self
+    }
+
+    /// Deducción a partir del entorno (`env` devuelve las variables no vacías).
+    fn from_env(env: &dyn Fn(&str) -> Option<String>, is_tty: bool, terminfo: Option<&Terminfo>) -> Self {
+        let term = env("TERM").unwrap_or_default();
+        let program = env("TERM_PROGRAM").unwrap_or_default();
+        let vte = env("VTE_VERSION").and_then(|v| v.parse::<u32>().ok()).unwrap_or(0);
+        let kitty = env("KITTY_WINDOW_ID").is_some() || term.contains("kitty");
+        let windows_terminal = env("WT_SESSION").is_some();
+        let colorterm = env("COLORTERM");
+        let modern = kitty || MODERN_TERMS.iter().any(|t| term.contains(t));
+        let dumb = term == "dumb";
+        let console = term == "linux" || term.starts_with("vt");
+        // Sin TERM (lo normal en Windows, también en Windows Terminal) y sin
+        // otras pistas se asume una consola con color pero sin extras.
+        let unknown = term.is_empty() && !windows_terminal && colorterm.is_none() && program.is_empty();
+        let basic = dumb || console || unknown;
+
+        let color_depth = if dumb {
+            ColorDepth::Monochrome
+        } else if colorterm.is_some_and(|c| c == "truecolor" || c == "24bit")
+            || modern
+            || windows_terminal
+            || ["iTerm.app", "WezTerm", "vscode", "ghostty"].contains(&program.as_str())
+            || terminfo.is_some_and(|t| t.true_color)
+        {
+            ColorDepth::TrueColor
+        } else if term.contains("256color") || terminfo.and_then(|t| t.colors).is_some_and(|c| c >= 256) {
+            ColorDepth::Ansi256
+        } else if terminfo.and_then(|t| t.colors).is_some_and(|c| c < 8) {
+            ColorDepth::Monochrome
+        } else {
+            ColorDepth::Ansi16
+        };
+
+        // La codificación la marca la primera variable de locale definida.
+        let unicode = match ["LC_ALL", "LC_CTYPE", "LANG"].iter().find_map(|name| env(name)) {
+            Some(locale) => {
+                let locale = locale.to_ascii_lowercase();
+                locale.contains("utf-8") || locale.contains("utf8")
+            }
+            None => windows_terminal || !basic,
+        };
+
+        let image_protocol = if kitty || program == "ghostty" {
+            Some(ImageProtocol::Kitty)
+        } else if program == "iTerm.app" || program == "WezTerm" {
+            Some(ImageProtocol::Iterm2)
+        } else if term.contains("foot") || term.contains("sixel") || term.starts_with("mlterm") {
+            Some(ImageProtocol::Sixel)
+        } else {
+            None
+        };
+
+        Self {
+            is_tty,
+            color_depth,
+            unicode,
+            synchronized_output: modern
+                || windows_terminal
+                || ["WezTerm", "iTerm.app", "ghostty"].contains(&program.as_str())
+                || vte >= 6800
+                || terminfo.is_some_and(|t| t.synchronized),
+            hyperlinks: !basic,
+            extended_underline: modern
+                || ["WezTerm", "iTerm.app", "vscode", "ghostty"].contains(&program.as_str())
+                || vte >= 5102
+                || terminfo.is_some_and(|t| t.extended_underline),
+            alternate_screen: terminfo.map_or(!dumb, |t| t.alternate_screen),
+            image_protocol,
+            terminal_name: None,
+        }
+    }
+
+    /// Incorpora las respuestas a XTVERSION (`DCS > | nombre ST`) y DA1 (`CSI ? … c`).
+    fn apply_replies(&mut self, reply: &[u8]) {
+        let (name, attributes) = parse_replies(reply);
+        if let Some(name) = name {
+            let lower = name.to_ascii_lowercase();
+            if MODERN_TERMS.iter().any(|t| lower.starts_with(t)) || lower.starts_with("iterm2") {
+                self.color_depth = ColorDepth::TrueColor;
+                self.synchronized_output = true;
+                self.extended_underline = true;
+            }
+            if lower.starts_with("kitty") || lower.starts_with("ghostty") {
+                self.image_protocol = Some(ImageProtocol::Kitty);
+            }
+            self.terminal_name = Some(name);
+        }
+        // El atributo 4 de DA1 anuncia soporte de gráficos sixel.
+        if attributes.contains(&4) && self.image_protocol.is_none() {
+            self.image_protocol = Some(ImageProtocol::Sixel);
+        }
+    }
+}
+
+/// Envía XTVERSION y DA1 y recoge la respuesta hasta la de DA1 (que llega la
+/// última) o hasta agotar `timeout`.
+#[cfg(unix)]
+fn query_terminal(timeout: Duration) -> Option<Vec<u8>> {
+    let raw = crossterm::terminal::is_raw_mode_enabled().unwrap_or(false);
+    if !raw {
+        crossterm::terminal::enable_raw_mode().ok()?;
+    }
+    let mut stdout = std::io::stdout();
+    let sent = stdout.write_all(b"\x1b[>0q\x1b[c").and_then(|_| stdout.flush()).is_ok();
+
+    let mut reply = Vec::new();
+    let deadline = Instant::now() + timeout;
+    while sent && parse_replies(&reply).1 == DA1_PENDING {
+        let Some(left) = deadline.checked_duration_since(Instant::now()) else { break };
+        if !read_available(&mut reply, left) {
+            break;
+        }
+    }
+    if !raw {
+        let _ = crossterm::terminal::disable_raw_mode();
+    }
+    (!reply.is_empty()).then_some(reply)
+}
+
+#[cfg(not(unix))]
+fn query_terminal(_timeout: Duration) -> Option<Vec<u8>> {
+    None
+}
+
+/// Espera como mucho `timeout` a que haya datos en la entrada estándar y
+/// añade a `reply` lo que se pueda leer sin bloquear. Devuelve `false` si no
+/// llegó nada.
+#[cfg(unix)]
+fn read_available(reply: &mut Vec<u8>, timeout: Duration) -> bool {
+    let mut fd = libc::pollfd { fd: libc::STDIN_FILENO, events: libc::POLLIN, revents: 0 };
+    let millis = timeout.as_millis().clamp(1, i32::MAX as u128) as libc::c_int;
+    // SAFETY: `fd` es un único `pollfd` válido durante la llamada.
+    if unsafe { libc::poll(&mut fd, 1, millis) } <= 0 || fd.revents & libc::POLLIN == 0 {
+        return false;
+    }
+    let mut buffer = [0u8; 256];
+    // SAFETY: `poll` indicó datos disponibles, así que `read` no bloquea, y
+    // `buffer` admite los `buffer.len()` bytes pedidos.
+    let read = unsafe { libc::read(libc::STDIN_FILENO, buffer.as_mut_ptr().cast(), buffer.len()) };
+    if read <= 0 {
+        return false;
+    }
+    reply.extend_from_slice(&buffer[..read as usize]);
+    true
+}
+
+/// Resultado de `parse_replies` mientras no ha llegado la respuesta a DA1.
+const DA1_PENDING: Vec<u16> = Vec::new();
+
+/// Extrae el nombre de XTVERSION y los atributos de DA1 de las respuestas.
+fn parse_replies(reply: &[u8]) -> (Option<String>, Vec<u16>) {
+    let text = String::from_utf8_lossy(reply);
+    let name = text.find("\x1bP>|").and_then(|start| {
+        let rest = &text[start + 4..];
+        rest.find("\x1b\\").map(|end| rest[..end].to_string())
+    });
+    let attributes = text
+        .find("\x1b[?")
+        .and_then(|start| {
+            let rest = &text[start + 3..];
+            let end = rest.find('c')?;
+            Some(rest[..end].split(';').filter_map(|p| p.parse().ok()).collect::<Vec<u16>>())
+        })
+        .filter(|attributes| !attributes.is_empty())
+        .unwrap_or(DA1_PENDING);
+    (name, attributes)
+}
+
+/// Lo que interesa de una entrada terminfo compilada.
+#[derive(Debug, Default, PartialEq, Eq)]
+struct Terminfo {
+    colors: Option<i32>,
+    alternate_screen: bool,
+    /// `Tc` o `RGB`.
+    true_color: bool,
+    /// `Sync`.
+    synchronized: bool,
+    /// `Smulx` o `Su`.
+    extended_underline: bool,
+}
+
+/// Índice de `colors` entre las capacidades numéricas estándar.
+const TERMINFO_COLORS: usize = 13;
+/// Índice de `enter_ca_mode` (`smcup`) entre las cadenas estándar.
+const TERMINFO_SMCUP: usize = 28;
+
+impl Terminfo {
+    /// Busca la entrada de `term` en los directorios habituales de terminfo.
+    fn load(term: &str, env: &dyn Fn(&str) -> Option<String>) -> Option<Self> {
+        let first = term.chars().next()?;
+        let mut dirs: Vec<PathBuf> = Vec::new();
+        dirs.extend(env("TERMINFO").map(PathBuf::from));
+        dirs.extend(env("HOME").map(|home| PathBuf::from(home).join(".terminfo")));
+        if let Some(list) = env("TERMINFO_DIRS") {
+            dirs.extend(list.split(':').filter(|d| !d.is_empty()).map(PathBuf::from));
+        }
+        dirs.extend(["/etc/terminfo", "/lib/terminfo", "/usr/share/terminfo", "/usr/lib/terminfo"].map(PathBuf::from));
+        dirs.iter()
+            .flat_map(|dir| [dir.join(first.to_string()).join(term), dir.join(format!("{:x}", first as u32)).join(term)])
+            .find_map(|path| std::fs::read(path).ok())
+            .and_then(|bytes| Self::parse(&bytes))
+    }
+
+    /// Interpreta el formato compilado (term(5)), incluida la sección extendida.
+    fn parse(bytes: &[u8]) -> Option<Self> {
+        let mut reader = Reader { bytes, pos: 0 };
+        let number_size = match reader.short()? {
+            0o432 => 2,
+            0o1036 => 4,
+            _ => return None,
+        };
+        let [names, bools, numbers, strings, table] = reader.counts()?;
+        reader.skip(names + bools)?;
+        reader.align();
+        let numbers: Vec<i32> = (0..numbers).map(|_| reader.number(number_size)).collect::<Option<_>>()?;
+        let offsets: Vec<i16> = (0..strings).map(|_| reader.short()).collect::<Option<_>>()?;
+        reader.skip(table)?;
+
+        let mut info = Terminfo {
+            colors: numbers.get(TERMINFO_COLORS).copied().filter(|&c| c >= 0),
+            alternate_screen: offsets.get(TERMINFO_SMCUP).is_some_and(|&o| o >= 0),
+            ..Default::default()
+        };
+
+        // Sección extendida (opcional): valores y, al final de su tabla, los nombres.
+        reader.align();
+        let Some([ext_bools, ext_numbers, ext_strings, _, ext_table]) = reader.counts() else { return Some(info) };
+        let bool_values = reader.take(ext_bools)?.to_vec();
+        reader.align();
+        let number_values: Vec<i32> = (0..ext_numbers).map(|_| reader.number(number_size)).collect::<Option<_>>()?;
+        let string_offsets: Vec<i16> = (0..ext_strings).map(|_| reader.short()).collect::<Option<_>>()?;
+        reader.skip((ext_bools + ext_numbers + ext_strings) * 2)?;
+        let table = reader.take(ext_table)?;
+        let entries: Vec<&[u8]> = table.split(|&b| b == 0).collect();
+        let name_count = ext_bools + ext_numbers + ext_strings;
+        let names = entries.len().checked_sub(1 + name_count).map(|start| &entries[start..start + name_count])?;
+
+        for (index, name) in names.iter().enumerate() {
+            let present = if index < ext_bools {
+                bool_values[index] == 1
+            } else if index < ext_bools + ext_numbers {
+                number_values[index - ext_bools] >= 0
+            } else {
+                string_offsets[index - ext_bools - ext_numbers] >= 0
+            };
+            match (*name, present) {
+                (b"Tc" | b"RGB", true) => info.true_color = true,
+                (b"Sync", true) => info.synchronized = true,
+                (b"Smulx" | b"Su", true) => info.extended_underline = true,
+                _ => {}
+            }
+        }
+        Some(info)
+    }
+}
+
+/// Lector de enteros little-endian sobre un archivo terminfo.
+struct Reader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, len: usize) -> Option<&'a [u8]> {
+        let slice = self.bytes.get(self.pos..self.pos + len)?;
+        self.pos += len;
+        Some(slice)
+    }
+
+    fn skip(&mut self, len: usize) -> Option<()> {
+        self.take(len).map(|_| ())
+    }
+
+    /// Las secciones numéricas empiezan en posición par.
+    fn align(&mut self) {
+        self.pos += self.pos % 2;
+    }
+
+    fn short(&mut self) -> Option<i16> {
+        self.take(2).map(|b| i16::from_le_bytes([b[0], b[1]]))
+    }
+
+    fn number(&mut self, size: usize) -> Option<i32> {
+        match size {
+            2 => self.short().map(i32::from),
+            _ => self.take(4).map(|b| i32::from_le_bytes([b[0], b[1], b[2], b[3]])),
+        }
+    }
+
+    /// Cinco contadores de cabecera (los negativos cuentan como cero).
+    fn counts(&mut self) -> Option<[usize; 5]> {
+        let mut counts = [0; 5];
+        for count in &mut counts {
+            *count = self.short()?.max(0) as usize;
+        }
+        Some(counts)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn env_of(vars: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> {
+        let vars: Vec<(String, String)> = vars.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect();
+        move |name| vars.iter().find(|(k, _)| k == name).map(|(_, v)| v.clone())
+    }
+
+    #[test]
+    fn test_detection_from_environment() {
+        let kitty = TerminalCapabilities::from_env(&env_of(&[("TERM", "xterm-kitty"), ("LANG", "es_ES.UTF-8")]), true, None);
+        assert_eq!(kitty.color_depth, ColorDepth::TrueColor);
+        assert!(kitty.unicode && kitty.synchronized_output && kitty.extended_underline && kitty.alternate_screen);
+        assert_eq!(kitty.image_protocol, Some(ImageProtocol::Kitty));
+
+        let plain = TerminalCapabilities::from_env(&env_of(&[("TERM", "xterm-256color"), ("LC_ALL", "C")]), true, None);
+        assert_eq!(plain.color_depth, ColorDepth::Ansi256);
+        assert!(!plain.unicode && !plain.synchronized_output);
+
+        let dumb = TerminalCapabilities::from_env(&env_of(&[("TERM", "dumb")]), false, None);
+        assert_eq!(dumb.color_depth, ColorDepth::Monochrome);
+        assert!(!dumb.alternate_screen && !dumb.hyperlinks && !dumb.is_tty);
+
+        // Windows Terminal no define TERM.
+        let windows = TerminalCapabilities::from_env(&env_of(&[("WT_SESSION", "0f6e")]), true, None);
+        assert_eq!(windows.color_depth, ColorDepth::TrueColor);
+        assert!(windows.unicode && windows.hyperlinks && windows.alternate_screen);
+        let console = TerminalCapabilities::from_env(&env_of(&[]), true, None);
+        assert_eq!(console.color_depth, ColorDepth::Ansi16);
+        assert!(console.alternate_screen && !console.hyperlinks);
+    }
+
+    #[test]
+    fn test_terminal_replies() {
+        let reply = b"\x1bP>|WezTerm 20240203\x1b\\\x1b[?62;4;22c";
+        assert_eq!(parse_replies(reply), (Some("WezTerm 20240203".to_string()), vec![62, 4, 22]));
+        assert_eq!(parse_replies(b"\x1b[?6"), (None, DA1_PENDING));
+
+        let mut caps = TerminalCapabilities::from_env(&env_of(&[("TERM", "xterm")]), true, None);
+        caps.apply_replies(reply);
+        assert!(caps.synchronized_output && caps.color_depth == ColorDepth::TrueColor);
+        assert_eq!(caps.image_protocol, Some(ImageProtocol::Sixel));
+    }
+
+    /// Entrada terminfo mínima: `colors#256`, `smcup` y las extendidas `Tc` y `Sync`.
+    fn compiled_entry() -> Vec<u8> {
+        let mut out = Vec::new();
+        let short = |out: &mut Vec<u8>, v: i16| out.extend_from_slice(&v.to_le_bytes());
+        let names = b"test|entrada\0";
+        for v in [0o432, names.len() as i16, 0, 14, 29, 3] { short(&mut out, v); }
+        out.extend_from_slice(names);
+        if out.len() % 2 == 1 { out.push(0); }
+        for i in 0..14 { short(&mut out, if i == TERMINFO_COLORS { 256 } else { -1 }); }
+        for i in 0..29 { short(&mut out, if i == TERMINFO_SMCUP { 0 } else { -1 }); }
+        out.extend_from_slice(b"x\0\0");
+        if out.len() % 2 == 1 { out.push(0); }
+        // Extendida: 1 booleana (Tc), 0 numéricas, 1 cadena (Sync).
+        let table = b"\x1b[?2026%?%p1%{1}%-%tl%eh%;\0Tc\0Sync\0";
+        for v in [1, 0, 1, 3, table.len() as i16] { short(&mut out, v); }
+        out.push(1);
+        out.push(0);
+        short(&mut out, 0);
+        short(&mut out, 0);
+        short(&mut out, 3);
+        out.extend_from_slice(table);
+        out
+    }
+
+    #[test]
+    fn test_terminfo_parsing() {
+        let info = Terminfo::parse(&compiled_entry()).unwrap();
+        assert_eq!(info, Terminfo {
+            colors: Some(256),
+            alternate_screen: true,
+            true_color: true,
+            synchronized: true,
+            extended_underline: false,
+        });
+        assert_eq!(Terminfo::parse(b"no es terminfo"), None);
+    }
+}
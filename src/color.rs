@@ -0,0 +1,409 @@
+// --- src/color.rs ---
+//! Interpretación de colores a partir de texto (archivos de configuración,
+//! flags de línea de comandos, etc.).
+//!
+//! Formatos aceptados:
+//! * Hexadecimal: `#rrggbb` y `#rgb`.
+//! * Funcional: `rgb(255, 128, 0)`, `rgb(100%, 50%, 0%)` y `hsl(30, 100%, 50%)`.
+//! * Nombres CSS/X11: `tomato`, `rebeccapurple`, `navy blue`... (sin distinguir mayúsculas,
+//!   espacios, guiones ni guiones bajos).
+//! * Colores ANSI: `bright-red` o por índice, `ansi:9` (0-15) y `256:208` / `xterm:208` (0-255).
+
+use crate::error::BannerError;
+use crate::style::Color;
+use owo_colors::{AnsiColors, DynColors, XtermColors};
+use std::fmt;
+use std::str::FromStr;
+
+/// Color interpretado desde texto.
+///
+/// `Color` es un alias de un tipo externo (`owo_colors::DynColors`), por lo que no
+/// puede implementar `FromStr` directamente; este envoltorio lo hace posible:
+///
+/// ```
+/// use vornix_banner::style::ColorSpec;
+/// let color: ColorSpec = "#ff8800".parse().unwrap();
+/// assert_eq!(color.0, vornix_banner::rgb(255, 136, 0));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ColorSpec(pub Color);
+
+impl FromStr for ColorSpec {
+    type Err = BannerError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_color(s).map(ColorSpec)
+    }
+}
+
+impl From<ColorSpec> for Color {
+    fn from(spec: ColorSpec) -> Self {
+        spec.0
+    }
+}
+
+impl fmt::Display for ColorSpec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (r, g, b) = crate::style::color_to_rgb(self.0);
+        write!(f, "#{:02x}{:02x}{:02x}", r, g, b)
+    }
+}
+
+/// Interpreta un color a partir de texto. Ver la documentación del módulo para los formatos.
+///
+/// # Errores
+/// Devuelve `BannerError::InvalidColor` con una descripción del problema.
+pub fn parse_color(input: &str) -> Result<Color, BannerError> {
+    let value = input.trim().to_ascii_lowercase();
+    if value.is_empty() {
+        return Err(invalid(input, "el valor está vacío"));
+    }
+
+    if let Some(hex) = value.strip_prefix('#') {
+        return parse_hex(input, hex);
+    }
+    if let Some(args) = function_args(&value, "rgb") {
+        return parse_rgb_function(input, args);
+    }
+    if let Some(args) = function_args(&value, "hsl") {
+        return parse_hsl_function(input, args);
+    }
+    if let Some(index) = value.strip_prefix("ansi:") {
+        let index = parse_index(input, index, 15)?;
+        return Ok(DynColors::Ansi(ANSI_COLORS[index as usize]));
+    }
+    if let Some(index) = value.strip_prefix("256:").or_else(|| value.strip_prefix("xterm:")) {
+        let index = parse_index(input, index, 255)?;
+        return Ok(DynColors::Xterm(XtermColors::from(index)));
+    }
+
+    let name: String = value.chars().filter(|c| !matches!(c, ' ' | '-' | '_')).collect();
+    if let Some(ansi) = name.strip_prefix("bright").and_then(ansi_by_name) {
+        return Ok(DynColors::Ansi(ANSI_COLORS[ansi + 8]));
+    }
+    CSS_COLORS
+        .iter()
+        .chain(X11_COLORS)
+        .find(|(candidate, _)| *candidate == name)
+        .map(|(_, (r, g, b))| DynColors::Rgb(*r, *g, *b))
+        .ok_or_else(|| invalid(input, "nombre de color desconocido (se esperaba un nombre CSS/X11, #hex, rgb(), hsl(), ansi:N o 256:N)"))
+}
+
+fn invalid(input: &str, reason: &str) -> BannerError {
+    BannerError::InvalidColor(format!("'{}': {}", input, reason))
+}
+
+/// Devuelve el contenido entre paréntesis de `name(...)`.
+fn function_args<'a>(value: &'a str, name: &str) -> Option<&'a str> {
+    value.strip_prefix(name)?.trim_start().strip_prefix('(')?.strip_suffix(')')
+}
+
+/// Separa argumentos por comas o espacios (sintaxis CSS clásica y moderna).
+fn split_args(args: &str) -> Vec<&str> {
+    args.split(|c: char| c == ',' || c.is_whitespace()).filter(|a| !a.is_empty()).collect()
+}
+
+fn parse_hex(input: &str, hex: &str) -> Result<Color, BannerError> {
+    if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(invalid(input, "contiene dígitos no hexadecimales"));
+    }
+    let channel = |s: &str| u8::from_str_radix(s, 16).expect("dígitos validados");
+    match hex.len() {
+        3 => {
+            let expand = |i: usize| channel(&hex[i..i + 1]) * 17;
+            Ok(DynColors::Rgb(expand(0), expand(1), expand(2)))
+        }
+        6 => Ok(DynColors::Rgb(channel(&hex[0..2]), channel(&hex[2..4]), channel(&hex[4..6]))),
+        n => Err(invalid(input, &format!("se esperaban 3 o 6 dígitos hexadecimales, hay {}", n))),
+    }
+}
+
+fn parse_rgb_function(input: &str, args: &str) -> Result<Color, BannerError> {
+    let parts = split_args(args);
+    if parts.len() != 3 {
+        return Err(invalid(input, "rgb() necesita exactamente 3 componentes"));
+    }
+    let mut channels = [0u8; 3];
+    for (channel, part) in channels.iter_mut().zip(&parts) {
+        *channel = match part.strip_suffix('%') {
+            Some(pct) => {
+                let pct = parse_number(input, pct, 0.0, 100.0)?;
+                (pct * 2.55).round() as u8
+            }
+            None => parse_number(input, part, 0.0, 255.0)?.round() as u8,
+        };
+    }
+    Ok(DynColors::Rgb(channels[0], channels[1], channels[2]))
+}
+
+fn parse_hsl_function(input: &str, args: &str) -> Result<Color, BannerError> {
+    let parts = split_args(args);
+    if parts.len() != 3 {
+        return Err(invalid(input, "hsl() necesita exactamente 3 componentes"));
+    }
+    let hue = parse_number(input, parts[0].trim_end_matches("deg"), f32::MIN, f32::MAX)?.rem_euclid(360.0);
+    let percent = |part: &str| -> Result<f32, BannerError> {
+        let value = part
+            .strip_suffix('%')
+            .ok_or_else(|| invalid(input, "la saturación y la luminosidad de hsl() deben ser porcentajes"))?;
+        Ok(parse_number(input, value, 0.0, 100.0)? / 100.0)
+    };
+    let (r, g, b) = hsl_to_rgb(hue, percent(parts[1])?, percent(parts[2])?);
+    Ok(DynColors::Rgb(r, g, b))
+}
+
+fn parse_number(input: &str, value: &str, min: f32, max: f32) -> Result<f32, BannerError> {
+    let number: f32 = value
+        .parse()
+        .map_err(|_| invalid(input, &format!("'{}' no es un número", value)))?;
+    if !(min..=max).contains(&number) {
+        return Err(invalid(input, &format!("{} está fuera del rango {}-{}", value, min, max)));
+    }
+    Ok(number)
+}
+
+fn parse_index(input: &str, value: &str, max: u8) -> Result<u8, BannerError> {
+    value
+        .trim()
+        .parse::<u8>()
+        .ok()
+        .filter(|i| *i <= max)
+        .ok_or_else(|| invalid(input, &format!("el índice debe estar entre 0 y {}", max)))
+}
+
+/// Conversión HSL → RGB (h en grados, s y l en 0.0-1.0).
+fn hsl_to_rgb(h: f32, s: f32, l: f32) -> (u8, u8, u8) {
+    let c = (1.0 - (2.0 * l - 1.0).abs()) * s;
+    let x = c * (1.0 - ((h / 60.0) % 2.0 - 1.0).abs());
+    let m = l - c / 2.0;
+    let (r, g, b) = match h as u32 {
+        0..=59 => (c, x, 0.0),
+        60..=119 => (x, c, 0.0),
+        120..=179 => (0.0, c, x),
+        180..=239 => (0.0, x, c),
+        240..=299 => (x, 0.0, c),
+        _ => (c, 0.0, x),
+    };
+    let to_u8 = |v: f32| ((v + m) * 255.0).round() as u8;
+    (to_u8(r), to_u8(g), to_u8(b))
+}
+
+const ANSI_COLORS: [AnsiColors; 16] = [
+    AnsiColors::Black, AnsiColors::Red, AnsiColors::Green, AnsiColors::Yellow,
+    AnsiColors::Blue, AnsiColors::Magenta, AnsiColors::Cyan, AnsiColors::White,
+    AnsiColors::BrightBlack, AnsiColors::BrightRed, AnsiColors::BrightGreen, AnsiColors::BrightYellow,
+    AnsiColors::BrightBlue, AnsiColors::BrightMagenta, AnsiColors::BrightCyan, AnsiColors::BrightWhite,
+];
+
+/// Índice (0-7) de un nombre de color ANSI básico.
+fn ansi_by_name(name: &str) -> Option<usize> {
+    ["black", "red", "green", "yellow", "blue", "magenta", "cyan", "white"]
+        .iter()
+        .position(|candidate| *candidate == name)
+}
+
+/// Nombres X11 que no forman parte de CSS.
+const X11_COLORS: &[(&str, (u8, u8, u8))] = &[
+    ("navyblue", (0, 0, 128)),
+    ("lightgoldenrod", (238, 221, 130)),
+    ("lightslateblue", (132, 112, 255)),
+    ("violetred", (208, 32, 144)),
+    ("webgray", (128, 128, 128)),
+    ("webgrey", (128, 128, 128)),
+    ("webgreen", (0, 128, 0)),
+    ("webmaroon", (128, 0, 0)),
+    ("webpurple", (128, 0, 128)),
+    ("x11gray", (190, 190, 190)),
+    ("x11grey", (190, 190, 190)),
+    ("x11green", (0, 255, 0)),
+    ("x11maroon", (176, 48, 96)),
+    ("x11purple", (160, 32, 240)),
+];
+
+/// Colores con nombre de CSS (los mismos que `owo_colors::CssColors`).
+const CSS_COLORS: &[(&str, (u8, u8, u8))] = &[
+    ("aliceblue", (240, 248, 255)),
+    ("antiquewhite", (250, 235, 215)),
+    ("aqua", (0, 255, 255)),
+    ("aquamarine", (127, 255, 212)),
+    ("azure", (240, 255, 255)),
+    ("beige", (245, 245, 220)),
+    ("bisque", (255, 228, 196)),
+    ("black", (0, 0, 0)),
+    ("blanchedalmond", (255, 235, 205)),
+    ("blue", (0, 0, 255)),
+    ("blueviolet", (138, 43, 226)),
+    ("brown", (165, 42, 42)),
+    ("burlywood", (222, 184, 135)),
+    ("cadetblue", (95, 158, 160)),
+    ("chartreuse", (127, 255, 0)),
+    ("chocolate", (210, 105, 30)),
+    ("coral", (255, 127, 80)),
+    ("cornflowerblue", (100, 149, 237)),
+    ("cornsilk", (255, 248, 220)),
+    ("crimson", (220, 20, 60)),
+    ("darkblue", (0, 0, 139)),
+    ("darkcyan", (0, 139, 139)),
+    ("darkgoldenrod", (184, 134, 11)),
+    ("darkgray", (169, 169, 169)),
+    ("darkgrey", (169, 169, 169)),
+    ("darkgreen", (0, 100, 0)),
+    ("darkkhaki", (189, 183, 107)),
+    ("darkmagenta", (139, 0, 139)),
+    ("darkolivegreen", (85, 107, 47)),
+    ("darkorange", (255, 140, 0)),
+    ("darkorchid", (153, 50, 204)),
+    ("darkred", (139, 0, 0)),
+    ("darksalmon", (233, 150, 122)),
+    ("darkseagreen", (143, 188, 143)),
+    ("darkslateblue", (72, 61, 139)),
+    ("darkslategray", (47, 79, 79)),
+    ("darkslategrey", (47, 79, 79)),
+    ("darkturquoise", (0, 206, 209)),
+    ("darkviolet", (148, 0, 211)),
+    ("deeppink", (255, 20, 147)),
+    ("deepskyblue", (0, 191, 255)),
+    ("dimgray", (105, 105, 105)),
+    ("dimgrey", (105, 105, 105)),
+    ("dodgerblue", (30, 144, 255)),
+    ("firebrick", (178, 34, 34)),
+    ("floralwhite", (255, 250, 240)),
+    ("forestgreen", (34, 139, 34)),
+    ("fuchsia", (255, 0, 255)),
+    ("gainsboro", (220, 220, 220)),
+    ("ghostwhite", (248, 248, 255)),
+    ("gold", (255, 215, 0)),
+    ("goldenrod", (218, 165, 32)),
+    ("gray", (128, 128, 128)),
+    ("grey", (128, 128, 128)),
+    ("green", (0, 128, 0)),
+    ("greenyellow", (173, 255, 47)),
+    ("honeydew", (240, 255, 240)),
+    ("hotpink", (255, 105, 180)),
+    ("indianred", (205, 92, 92)),
+    ("indigo", (75, 0, 130)),
+    ("ivory", (255, 255, 240)),
+    ("khaki", (240, 230, 140)),
+    ("lavender", (230, 230, 250)),
+    ("lavenderblush", (255, 240, 245)),
+    ("lawngreen", (124, 252, 0)),
+    ("lemonchiffon", (255, 250, 205)),
+    ("lightblue", (173, 216, 230)),
+    ("lightcoral", (240, 128, 128)),
+    ("lightcyan", (224, 255, 255)),
+    ("lightgoldenrodyellow", (250, 250, 210)),
+    ("lightgray", (211, 211, 211)),
+    ("lightgrey", (211, 211, 211)),
+    ("lightgreen", (144, 238, 144)),
+    ("lightpink", (255, 182, 193)),
+    ("lightsalmon", (255, 160, 122)),
+    ("lightseagreen", (32, 178, 170)),
+    ("lightskyblue", (135, 206, 250)),
+    ("lightslategray", (119, 136, 153)),
+    ("lightslategrey", (119, 136, 153)),
+    ("lightsteelblue", (176, 196, 222)),
+    ("lightyellow", (255, 255, 224)),
+    ("lime", (0, 255, 0)),
+    ("limegreen", (50, 205, 50)),
+    ("linen", (250, 240, 230)),
+    ("magenta", (255, 0, 255)),
+    ("maroon", (128, 0, 0)),
+    ("mediumaquamarine", (102, 205, 170)),
+    ("mediumblue", (0, 0, 205)),
+    ("mediumorchid", (186, 85, 211)),
+    ("mediumpurple", (147, 112, 219)),
+    ("mediumseagreen", (60, 179, 113)),
+    ("mediumslateblue", (123, 104, 238)),
+    ("mediumspringgreen", (0, 250, 154)),
+    ("mediumturquoise", (72, 209, 204)),
+    ("mediumvioletred", (199, 21, 133)),
+    ("midnightblue", (25, 25, 112)),
+    ("mintcream", (245, 255, 250)),
+    ("mistyrose", (255, 228, 225)),
+    ("moccasin", (255, 228, 181)),
+    ("navajowhite", (255, 222, 173)),
+    ("navy", (0, 0, 128)),
+    ("oldlace", (253, 245, 230)),
+    ("olive", (128, 128, 0)),
+    ("olivedrab", (107, 142, 35)),
+    ("orange", (255, 165, 0)),
+    ("orangered", (255, 69, 0)),
+    ("orchid", (218, 112, 214)),
+    ("palegoldenrod", (238, 232, 170)),
+    ("palegreen", (152, 251, 152)),
+    ("paleturquoise", (175, 238, 238)),
+    ("palevioletred", (219, 112, 147)),
+    ("papayawhip", (255, 239, 213)),
+    ("peachpuff", (255, 218, 185)),
+    ("peru", (205, 133, 63)),
+    ("pink", (255, 192, 203)),
+    ("plum", (221, 160, 221)),
+    ("powderblue", (176, 224, 230)),
+    ("purple", (128, 0, 128)),
+    ("rebeccapurple", (102, 51, 153)),
+    ("red", (255, 0, 0)),
+    ("rosybrown", (188, 143, 143)),
+    ("royalblue", (65, 105, 225)),
+    ("saddlebrown", (139, 69, 19)),
+    ("salmon", (250, 128, 114)),
+    ("sandybrown", (244, 164, 96)),
+    ("seagreen", (46, 139, 87)),
+    ("seashell", (255, 245, 238)),
+    ("sienna", (160, 82, 45)),
+    ("silver", (192, 192, 192)),
+    ("skyblue", (135, 206, 235)),
+    ("slateblue", (106, 90, 205)),
+    ("slategray", (112, 128, 144)),
+    ("slategrey", (112, 128, 144)),
+    ("snow", (255, 250, 250)),
+    ("springgreen", (0, 255, 127)),
+    ("steelblue", (70, 130, 180)),
+    ("tan", (210, 180, 140)),
+    ("teal", (0, 128, 128)),
+    ("thistle", (216, 191, 216)),
+    ("tomato", (255, 99, 71)),
+    ("turquoise", (64, 224, 208)),
+    ("violet", (238, 130, 238)),
+    ("wheat", (245, 222, 179)),
+    ("white", (255, 255, 255)),
+    ("whitesmoke", (245, 245, 245)),
+    ("yellow", (255, 255, 0)),
+    ("yellowgreen", (154, 205, 50)),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::style::rgb;
+
+    #[test]
+    fn test_parse_hex_and_functions() {
+        assert_eq!(parse_color("#FF8800").unwrap(), rgb(255, 136, 0));
+        assert_eq!(parse_color("#f80").unwrap(), rgb(255, 136, 0));
+        assert_eq!(parse_color("rgb(10, 20, 30)").unwrap(), rgb(10, 20, 30));
+        assert_eq!(parse_color("rgb(100% 0% 50%)").unwrap(), rgb(255, 0, 128));
+        assert_eq!(parse_color("hsl(120, 100%, 50%)").unwrap(), rgb(0, 255, 0));
+        assert_eq!(parse_color("hsl(0deg 0% 100%)").unwrap(), rgb(255, 255, 255));
+    }
+
+    #[test]
+    fn test_parse_names_and_indices() {
+        assert_eq!(parse_color("Tomato").unwrap(), rgb(255, 99, 71));
+        assert_eq!(parse_color("rebecca-purple").unwrap(), rgb(102, 51, 153));
+        assert_eq!(parse_color("navy blue").unwrap(), rgb(0, 0, 128));
+        assert_eq!(parse_color("bright red").unwrap(), DynColors::Ansi(AnsiColors::BrightRed));
+        assert_eq!(parse_color("ansi:9").unwrap(), DynColors::Ansi(AnsiColors::BrightRed));
+        assert_eq!(parse_color("256:208").unwrap(), DynColors::Xterm(XtermColors::from(208)));
+        assert_eq!("teal".parse::<ColorSpec>().unwrap().to_string(), "#008080");
+    }
+
+    #[test]
+    fn test_parse_errors_are_descriptive() {
+        for bad in ["", "#12", "#gggggg", "rgb(1,2)", "rgb(300,0,0)", "hsl(0,50,50)", "ansi:16", "nocolor"] {
+            match parse_color(bad) {
+                Err(BannerError::InvalidColor(msg)) => assert!(msg.contains(bad), "{}", msg),
+                other => panic!("'{}' debería fallar, se obtuvo {:?}", bad, other),
+            }
+        }
+    }
+}
@@ -0,0 +1,267 @@
+// --- src/effects.rs ---
+//! Efectos de aparición procedurales: generan los frames de una `Animation`
+//! a partir del contenido ya renderizado de un banner FIGlet.
+
+use crate::animation::{Animation, Frame};
+use crate::utils::Rng;
+use std::time::Duration;
+
+/// Curva de aceleración aplicada al progreso de un efecto.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Easing {
+    #[default]
+    Linear,
+    /// Empieza lento y acelera (cuadrática).
+    EaseIn,
+    /// Empieza rápido y frena (cuadrática).
+    EaseOut,
+    /// Lento al principio y al final.
+    EaseInOut,
+}
+
+impl Easing {
+    /// Transforma un progreso lineal `t` (0.0-1.0) según la curva.
+    pub fn apply(self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Easing::Linear => t,
+            Easing::EaseIn => t * t,
+            Easing::EaseOut => t * (2.0 - t),
+            Easing::EaseInOut => {
+                if t < 0.5 { 2.0 * t * t } else { -1.0 + (4.0 - 2.0 * t) * t }
+            }
+        }
+    }
+}
+
+/// Borde de la pantalla desde el que entra o se descubre el contenido.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Edge {
+    Left,
+    Right,
+    Top,
+    Bottom,
+}
+
+/// Tipos de efecto de aparición.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RevealEffect {
+    /// Revela columna a columna, de izquierda a derecha, con un cursor en el frente.
+    Typewriter,
+    /// Revela línea a línea, de arriba abajo.
+    LineByLine,
+    /// El banner completo se desliza hacia su posición desde el borde indicado.
+    SlideIn(Edge),
+    /// Una cortina descubre el banner empezando por el borde indicado.
+    Wipe(Edge),
+    /// Cada carácter muestra símbolos aleatorios hasta "descifrarse".
+    Scramble,
+}
+
+/// Caracteres usados por el efecto `Scramble`.
+const SCRAMBLE_GLYPHS: &[char] = &['#', '%', '&', '@', '$', '*', '+', '=', '?', '/', '\\', '|', '<', '>', '~'];
+/// Cursor del efecto `Typewriter`.
+const TYPEWRITER_CURSOR: char = '█';
+
+/// Configuración de un efecto de aparición.
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use vornix_banner::{Banner, Easing, Edge, Reveal, RevealEffect};
+///
+/// Banner::new("Hola")
+///     .with_reveal(Reveal::new(RevealEffect::SlideIn(Edge::Left))
+///         .duration(Duration::from_millis(800))
+///         .easing(Easing::EaseOut))
+///     .display()
+///     .unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct Reveal {
+    pub effect: RevealEffect,
+    pub duration: Duration,
+    pub easing: Easing,
+    /// Frames por segundo generados.
+    pub fps: u32,
+    /// Semilla para los efectos aleatorios (`Scramble`).
+    pub seed: u64,
+}
+
+impl Reveal {
+    /// Crea un efecto con valores por defecto: 1 segundo, lineal, 30 fps.
+    pub fn new(effect: RevealEffect) -> Self {
+        Self { effect, duration: Duration::from_secs(1), easing: Easing::Linear, fps: 30, seed: 0x5EED }
+    }
+
+    pub fn duration(mut self, duration: Duration) -> Self { self.duration = duration; self }
+    pub fn easing(mut self, easing: Easing) -> Self { self.easing = easing; self }
+    pub fn fps(mut self, fps: u32) -> Self { self.fps = fps.max(1); self }
+    pub fn seed(mut self, seed: u64) -> Self { self.seed = seed; self }
+
+    /// Número de frames a generar (al menos 2: inicio y banner completo).
+    fn frame_count(&self) -> usize {
+        ((self.duration.as_secs_f32() * self.fps as f32).round() as usize).max(2)
+    }
+
+    /// Genera los frames del efecto para `content`. El último frame es siempre
+    /// el banner completo y todos tienen las mismas dimensiones.
+    pub fn frames(&self, content: &[String]) -> Vec<Frame> {
+        let grid = CharGrid::new(content);
+        let count = self.frame_count();
+        let mut rng = Rng::new(self.seed);
+        // Momento (0.0-1.0) en que se descifra cada celda para `Scramble`.
+        let thresholds: Vec<Vec<f32>> = grid
+            .cells
+            .iter()
+            .map(|row| row.iter().map(|_| rng.next_f32()).collect())
+            .collect();
+
+        (0..count)
+            .map(|i| {
+                let progress = self.easing.apply(i as f32 / (count - 1) as f32);
+                match self.effect {
+                    RevealEffect::Typewriter => grid.typewriter(progress),
+                    RevealEffect::LineByLine => grid.wipe(Edge::Top, progress),
+                    RevealEffect::Wipe(edge) => grid.wipe(edge, progress),
+                    RevealEffect::SlideIn(edge) => grid.slide(edge, progress),
+                    RevealEffect::Scramble => grid.scramble(progress, &thresholds, &mut rng),
+                }
+            })
+            .collect()
+    }
+
+    /// Genera una `Animation` de una sola pasada con el retardo correspondiente a `fps`.
+    pub fn animation(&self, content: &[String]) -> Animation {
+        Animation::new(self.frames(content), (1000 / self.fps.max(1)) as u64)
+    }
+}
+
+/// Contenido como rejilla rectangular de caracteres (líneas rellenadas con espacios).
+struct CharGrid {
+    cells: Vec<Vec<char>>,
+    width: usize,
+}
+
+impl CharGrid {
+    fn new(content: &[String]) -> Self {
+        let width = content.iter().map(|l| l.chars().count()).max().unwrap_or(0);
+        let cells = content
+            .iter()
+            .map(|line| {
+                let mut row: Vec<char> = line.chars().collect();
+                row.resize(width, ' ');
+                row
+            })
+            .collect();
+        Self { cells, width }
+    }
+
+    fn height(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// Construye un frame eligiendo el carácter de cada celda.
+    fn render(&self, mut cell: impl FnMut(usize, usize, char) -> char) -> Frame {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(y, row)| row.iter().enumerate().map(|(x, ch)| cell(x, y, *ch)).collect())
+            .collect()
+    }
+
+    fn typewriter(&self, progress: f32) -> Frame {
+        let cutoff = (progress * self.width as f32).round() as usize;
+        self.render(|x, _, ch| match x.cmp(&cutoff) {
+            std::cmp::Ordering::Less => ch,
+            std::cmp::Ordering::Equal if cutoff < self.width => TYPEWRITER_CURSOR,
+            _ => ' ',
+        })
+    }
+
+    fn wipe(&self, edge: Edge, progress: f32) -> Frame {
+        let (w, h) = (self.width, self.height());
+        let cols = (progress * w as f32).round() as usize;
+        let rows = (progress * h as f32).round() as usize;
+        self.render(|x, y, ch| {
+            let visible = match edge {
+                Edge::Left => x < cols,
+                Edge::Right => x >= w - cols,
+                Edge::Top => y < rows,
+                Edge::Bottom => y >= h - rows,
+            };
+            if visible { ch } else { ' ' }
+        })
+    }
+
+    fn slide(&self, edge: Edge, progress: f32) -> Frame {
+        let (w, h) = (self.width as isize, self.height() as isize);
+        let dx = ((1.0 - progress) * w as f32).round() as isize;
+        let dy = ((1.0 - progress) * h as f32).round() as isize;
+        // Desplazamiento del contenido respecto a su posición final.
+        let (ox, oy) = match edge {
+            Edge::Left => (-dx, 0),
+            Edge::Right => (dx, 0),
+            Edge::Top => (0, -dy),
+            Edge::Bottom => (0, dy),
+        };
+        self.render(|x, y, _| {
+            let (sx, sy) = (x as isize - ox, y as isize - oy);
+            if (0..w).contains(&sx) && (0..h).contains(&sy) {
+                self.cells[sy as usize][sx as usize]
+            } else {
+                ' '
+            }
+        })
+    }
+
+    fn scramble(&self, progress: f32, thresholds: &[Vec<f32>], rng: &mut Rng) -> Frame {
+        self.render(|x, y, ch| {
+            if ch == ' ' || progress >= 1.0 || thresholds[y][x] < progress {
+                ch
+            } else {
+                SCRAMBLE_GLYPHS[rng.below(SCRAMBLE_GLYPHS.len())]
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn content() -> Vec<String> {
+        vec!["abc".to_string(), "de".to_string()]
+    }
+
+    #[test]
+    fn test_every_effect_ends_with_full_content() {
+        let effects = [
+            RevealEffect::Typewriter,
+            RevealEffect::LineByLine,
+            RevealEffect::SlideIn(Edge::Right),
+            RevealEffect::Wipe(Edge::Bottom),
+            RevealEffect::Scramble,
+        ];
+        for effect in effects {
+            let frames = Reveal::new(effect).fps(10).frames(&content());
+            assert_eq!(frames.len(), 10);
+            assert_eq!(frames.last().unwrap(), &vec!["abc".to_string(), "de ".to_string()], "{:?}", effect);
+            assert!(frames.iter().all(|f| f.len() == 2 && f.iter().all(|l| l.chars().count() == 3)));
+        }
+    }
+
+    #[test]
+    fn test_first_frame_is_hidden() {
+        let frames = Reveal::new(RevealEffect::Wipe(Edge::Left)).fps(4).frames(&content());
+        assert_eq!(frames[0], vec!["   ".to_string(), "   ".to_string()]);
+        let slide = Reveal::new(RevealEffect::SlideIn(Edge::Top)).fps(4).frames(&content());
+        assert_eq!(slide[0], vec!["   ".to_string(), "   ".to_string()]);
+    }
+
+    #[test]
+    fn test_scramble_is_reproducible() {
+        let a = Reveal::new(RevealEffect::Scramble).seed(7).frames(&content());
+        let b = Reveal::new(RevealEffect::Scramble).seed(7).frames(&content());
+        assert_eq!(a, b);
+    }
+}
@@ -27,6 +27,14 @@ pub enum BannerError {
     #[error("Configuración de animación inválida: {0}")]
     InvalidAnimation(String),
 
+    /// No se pudo interpretar un color escrito como texto.
+    #[error("Color inválido: {0}")]
+    InvalidColor(String),
+
+    /// Error al cargar o interpretar un archivo de temas.
+    #[error("Error al cargar el tema: {0}")]
+    ThemeLoad(String),
+
     /// No se pudo determinar el tamaño de la terminal.
     #[error("No se pudo determinar el tamaño de la terminal: {0}")]
     TerminalSize(io::Error),
@@ -48,6 +56,8 @@ impl From<BannerError> for io::Error {
             BannerError::FontLoad(_) => io::ErrorKind::InvalidData,
             BannerError::FigletGeneration(_) => io::ErrorKind::InvalidData,
             BannerError::InvalidAnimation(_) => io::ErrorKind::InvalidInput,
+            BannerError::InvalidColor(_) => io::ErrorKind::InvalidInput,
+            BannerError::ThemeLoad(_) => io::ErrorKind::InvalidData,
             BannerError::Internal(_) => io::ErrorKind::Other,
             // Si añadiéramos un BannerError::Crossterm(e), mapearíamos 'e' aquí
         };
@@ -0,0 +1,175 @@
+// --- src/export/html.rs ---
+//! Exportación a HTML: un bloque `<pre>` con un `<span>` por tramo de estilo,
+//! con estilos en línea (apto para correo) o clases CSS.
+
+use super::{cell_colors, escape_xml, hex, row_runs, DEFAULT_BACKGROUND, DEFAULT_FOREGROUND};
+use crate::error::BannerError;
+use crate::grid::{CellStyle, Grid};
+use crate::style::Attribute;
+use std::fmt::Write as _;
+use std::io::Write;
+
+/// Prefijo de las clases CSS generadas.
+const CLASS_PREFIX: &str = "vb";
+
+/// Opciones de `Banner::to_html`.
+///
+/// ```no_run
+/// use vornix_banner::{Banner, HtmlOptions};
+///
+/// let mut html = Vec::new();
+/// Banner::new("Informe").to_html(&HtmlOptions::new().css_classes(), &mut html)?;
+/// # Ok::<(), vornix_banner::BannerError>(())
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct HtmlOptions {
+    /// Usa clases CSS (con un bloque `<style>`) en lugar de estilos en línea.
+    pub css_classes: bool,
+    /// Genera una página completa (`<!DOCTYPE html>`) con este título, en
+    /// lugar de un fragmento.
+    pub standalone: Option<String>,
+}
+
+impl HtmlOptions {
+    /// Fragmento con estilos en línea.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn css_classes(mut self) -> Self { self.css_classes = true; self }
+    pub fn standalone(mut self, title: &str) -> Self { self.standalone = Some(title.to_string()); self }
+}
+
+/// Declaraciones CSS de un estilo de celda. Vacío si la celda no tiene estilo.
+fn css(style: &CellStyle) -> String {
+    let mut out = String::new();
+    if style.fg.is_some() || style.bg.is_some() || style.has(Attribute::Reverse) {
+        let (fg, bg) = cell_colors(style);
+        let _ = write!(out, "color:{};", hex(fg));
+        if let Some(bg) = bg {
+            let _ = write!(out, "background-color:{};", hex(bg));
+        }
+    }
+    if style.has(Attribute::Bold) { out.push_str("font-weight:bold;"); }
+    if style.has(Attribute::Italic) { out.push_str("font-style:italic;"); }
+    if style.has(Attribute::Dim) { out.push_str("opacity:0.6;"); }
+    if style.has(Attribute::Hidden) { out.push_str("visibility:hidden;"); }
+    let underline = [Attribute::Underline, Attribute::DoubleUnderline, Attribute::CurlyUnderline]
+        .iter()
+        .any(|a| style.has(*a));
+    let strike = style.has(Attribute::Strikethrough);
+    if underline || strike {
+        out.push_str("text-decoration:");
+        if underline { out.push_str(" underline"); }
+        if strike { out.push_str(" line-through"); }
+        if style.has(Attribute::DoubleUnderline) { out.push_str(" double"); }
+        if style.has(Attribute::CurlyUnderline) { out.push_str(" wavy"); }
+        if let Some(color) = style.underline_color { let _ = write!(out, " {}", hex(color.to_rgb())); }
+        out.push(';');
+    }
+    out
+}
+
+/// Escribe `grid` como HTML.
+pub(crate) fn write_html(grid: &Grid, options: &HtmlOptions, mut writer: impl Write) -> Result<(), BannerError> {
+    // Clases asignadas por orden de aparición a cada conjunto de declaraciones.
+    let mut classes: Vec<String> = Vec::new();
+    let mut body = String::new();
+    for y in 0..grid.height() {
+        let mut line = String::new();
+        let runs = row_runs(grid, y);
+        // Sin los espacios finales sin estilo, como en la terminal.
+        let last = runs.iter().rposition(|r| !(css(r.style).is_empty() && r.style.link.is_none() && r.text.trim().is_empty()));
+        for run in runs.iter().take(last.map_or(0, |i| i + 1)) {
+            let declarations = css(run.style);
+            let mut text = escape_xml(&run.text);
+            if !declarations.is_empty() {
+                let attribute = if options.css_classes {
+                    let index = classes.iter().position(|c| *c == declarations).unwrap_or_else(|| {
+                        classes.push(declarations.clone());
+                        classes.len() - 1
+                    });
+                    format!("class=\"{}-{}\"", CLASS_PREFIX, index)
+                } else {
+                    format!("style=\"{}\"", declarations)
+                };
+                text = format!("<span {}>{}</span>", attribute, text);
+            }
+            match &run.style.link {
+                Some(url) => { let _ = write!(line, "<a href=\"{}\">{}</a>", escape_xml(url), text); }
+                None => line.push_str(&text),
+            }
+        }
+        body.push_str(line.trim_end_matches(' '));
+        body.push('\n');
+    }
+
+    let pre_css = format!(
+        "background-color:{};color:{};font-family:ui-monospace,'DejaVu Sans Mono',Menlo,Consolas,monospace;line-height:1.2;padding:1em;",
+        hex(DEFAULT_BACKGROUND),
+        hex(DEFAULT_FOREGROUND)
+    );
+    let mut out = String::new();
+    if let Some(title) = &options.standalone {
+        let _ = writeln!(out, "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>", escape_xml(title));
+    }
+    if options.css_classes {
+        out.push_str("<style>\n");
+        let _ = writeln!(out, "pre.{} {{ {} }}", CLASS_PREFIX, pre_css);
+        for (index, declarations) in classes.iter().enumerate() {
+            let _ = writeln!(out, ".{}-{} {{ {} }}", CLASS_PREFIX, index, declarations);
+        }
+        out.push_str("</style>\n");
+    }
+    if options.standalone.is_some() {
+        let _ = writeln!(out, "</head>\n<body style=\"background-color:{};\">", hex(DEFAULT_BACKGROUND));
+    }
+    if options.css_classes {
+        let _ = write!(out, "<pre class=\"{}\">", CLASS_PREFIX);
+    } else {
+        let _ = write!(out, "<pre style=\"{}\">", pre_css);
+    }
+    out.push_str(&body);
+    out.push_str("</pre>\n");
+    if options.standalone.is_some() {
+        out.push_str("</body>\n</html>\n");
+    }
+    writer.write_all(out.as_bytes())?;
+    writer.flush()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::animation::Animation;
+    use crate::banner::Banner;
+    use crate::style::{rgb, Style};
+
+    fn banner() -> Banner {
+        Banner::from_animation(Animation::new(vec![vec!["<a> & b  ".to_string()]], 100))
+            .with_style(Style::new().fg(rgb(255, 0, 0)).bold())
+            .padding_top(0)
+    }
+
+    #[test]
+    fn test_inline_styles_and_escaping() {
+        let mut html = Vec::new();
+        banner().to_html(&HtmlOptions::new(), &mut html).unwrap();
+        let html = String::from_utf8(html).unwrap();
+        assert!(html.starts_with("<pre style=\"background-color:#1e1e1e;"));
+        assert!(html.contains("<span style=\"color:#ff0000;font-weight:bold;\">&lt;a&gt; &amp; b  </span>\n</pre>"));
+    }
+
+    #[test]
+    fn test_classes_and_standalone_page() {
+        let mut html = Vec::new();
+        banner().to_html(&HtmlOptions::new().css_classes().standalone("Informe <1>"), &mut html).unwrap();
+        let html = String::from_utf8(html).unwrap();
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains("<title>Informe &lt;1&gt;</title>"));
+        assert!(html.contains(".vb-0 { color:#ff0000;font-weight:bold; }"));
+        assert!(html.contains("<span class=\"vb-0\">"));
+        assert!(html.ends_with("</html>\n"));
+    }
+}
@@ -0,0 +1,138 @@
+// --- src/export/mod.rs ---
+//! Exportación de banners y animaciones a formatos de documento. Los frames se
+//! componen exactamente igual que en la terminal (estilo, degradado, borde,
+//! alineación) sobre una rejilla de celdas, y cada formato la vuelca a su manera.
+
+pub(crate) mod html;
+#[cfg(feature = "raster")]
+pub(crate) mod raster;
+pub(crate) mod svg;
+
+use crate::animation::Animation;
+use crate::error::BannerError;
+use crate::grid::{CellStyle, Grid};
+use crate::layout::{content_width, Alignment, BackgroundMode};
+use crate::renderer::{compose_frame, FallbackFrame, RenderOptions, ScreenMode};
+use crate::style::{Attribute, ColorMode, Style};
+use std::time::Duration;
+
+/// Color resuelto a sus componentes RGB.
+pub(crate) type Rgb8 = (u8, u8, u8);
+
+/// Color de texto de la "terminal" de los documentos exportados.
+pub(crate) const DEFAULT_FOREGROUND: Rgb8 = (229, 229, 229);
+/// Color de fondo de la "terminal" de los documentos exportados.
+pub(crate) const DEFAULT_BACKGROUND: Rgb8 = (30, 30, 30);
+
+/// Frames ya compuestos, listos para exportar.
+pub(crate) struct Composition {
+    pub width: u16,
+    pub height: u16,
+    /// Cada frame con el tiempo que permanece visible.
+    pub frames: Vec<(Grid, Duration)>,
+}
+
+/// Compone `passes` pasadas de `animation` con `options`. El tamaño es el del
+/// contenido decorado más el relleno superior; el último frame de la última
+/// pasada incluye `hold_last` si la animación es finita.
+pub(crate) fn compose(animation: &Animation, options: &RenderOptions, passes: usize) -> Result<Composition, BannerError> {
+    if animation.frames.is_empty() {
+        return Err(BannerError::InvalidAnimation("No hay frames que exportar".to_string()));
+    }
+    let decorated: Vec<_> = animation.frames.iter().map(|f| options.decorate(f)).collect();
+    let width = decorated.iter().map(|f| content_width(f)).max().unwrap_or(0).max(1);
+    let height = (options.padding_top as usize + decorated.iter().map(Vec::len).max().unwrap_or(0)).clamp(1, u16::MAX as usize) as u16;
+    // En un documento no hay TTY que consultar: solo `ColorMode::Never` desactiva los escapes.
+    let escapes = options.color_mode != ColorMode::Never;
+
+    let mut frames = Vec::with_capacity(animation.frames.len() * passes);
+    for _ in 0..passes {
+        for (index, frame) in animation.frames.iter().enumerate() {
+            let grid = compose_frame(frame, options, &animation.links, escapes, (width, height));
+            frames.push((grid, animation.delay_for(index)));
+        }
+    }
+    if animation.repeat.is_some()
+        && let Some((_, last)) = frames.last_mut()
+    {
+        *last += animation.hold_last;
+    }
+    Ok(Composition { width, height, frames })
+}
+
+/// Opciones usadas al exportar una `Animation` sin banner: sin estilo, sin
+/// decoración y alineada a la izquierda.
+pub(crate) fn plain_options(style: &Style) -> RenderOptions<'_> {
+    RenderOptions {
+        style,
+        alignment: Alignment::Left,
+        padding_top: 0,
+        border: None,
+        hyperlink: None,
+        color_mode: ColorMode::Always,
+        background_mode: BackgroundMode::Glyphs,
+        screen_mode: ScreenMode::default(),
+        fallback_width: 80,
+        fallback_frame: FallbackFrame::default(),
+        hyperlinks: true,
+    }
+}
+
+/// Colores efectivos (texto, fondo) de una celda, con `Reverse` ya aplicado.
+/// El fondo es `None` cuando la celda usa el de la terminal.
+pub(crate) fn cell_colors(style: &CellStyle) -> (Rgb8, Option<Rgb8>) {
+    let fg = style.fg.map(|c| c.to_rgb());
+    let bg = style.bg.map(|c| c.to_rgb());
+    if style.has(Attribute::Reverse) {
+        (bg.unwrap_or(DEFAULT_BACKGROUND), Some(fg.unwrap_or(DEFAULT_FOREGROUND)))
+    } else {
+        (fg.unwrap_or(DEFAULT_FOREGROUND), bg)
+    }
+}
+
+/// `#rrggbb` de un color.
+pub(crate) fn hex((r, g, b): Rgb8) -> String {
+    format!("#{:02x}{:02x}{:02x}", r, g, b)
+}
+
+/// Escapa el texto para XML/HTML.
+pub(crate) fn escape_xml(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for ch in text.chars() {
+        match ch {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+/// Tramo de celdas consecutivas de una fila con el mismo estilo.
+pub(crate) struct Run<'g> {
+    /// Columna inicial y número de columnas que ocupa.
+    pub column: u16,
+    pub columns: u16,
+    pub text: String,
+    pub style: &'g CellStyle,
+}
+
+/// Divide la fila `y` en tramos de estilo uniforme. Las celdas de
+/// continuación de los caracteres anchos se cuentan en el tramo de su carácter.
+pub(crate) fn row_runs(grid: &Grid, y: u16) -> Vec<Run<'_>> {
+    let mut runs: Vec<Run<'_>> = Vec::new();
+    for x in 0..grid.width() {
+        let cell = grid.cell(x, y);
+        match runs.last_mut() {
+            Some(run) if cell.is_continuation() || *run.style == cell.style => {
+                run.columns += 1;
+                if !cell.is_continuation() { run.text.push(cell.ch); }
+            }
+            _ => runs.push(Run { column: x, columns: 1, text: cell.ch.to_string(), style: &cell.style }),
+        }
+    }
+    runs
+}
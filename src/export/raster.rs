@@ -0,0 +1,267 @@
+// --- src/export/raster.rs ---
+//! Rasterización a PNG y GIF animado con una fuente de mapa de bits 8x8
+//! incrustada (`font8x8`), sin depender de las fuentes del sistema.
+
+use super::{cell_colors, Composition, Rgb8, DEFAULT_BACKGROUND};
+use crate::animation::Animation;
+use crate::error::BannerError;
+use crate::grid::Grid;
+use crate::renderer::RenderOptions;
+use crate::style::Attribute;
+use font8x8::UnicodeFonts;
+use std::io::{self, Write};
+use std::time::Duration;
+
+/// Ancho y alto de una celda antes de escalar: el glifo 8x8 se estira en
+/// vertical para conservar la proporción de una terminal.
+const CELL_WIDTH: usize = 8;
+const CELL_HEIGHT: usize = 16;
+
+/// Opciones de rasterización (`to_png`, `to_gif`).
+#[derive(Debug, Clone)]
+pub struct RasterOptions {
+    /// Píxeles por píxel de la fuente (1 = celdas de 8x16).
+    pub scale: u32,
+    /// Margen alrededor del banner, en celdas.
+    pub margin: u16,
+}
+
+impl Default for RasterOptions {
+    fn default() -> Self {
+        Self { scale: 2, margin: 1 }
+    }
+}
+
+impl RasterOptions {
+    /// Escala 2 (celdas de 16x32) y una celda de margen.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn scale(mut self, scale: u32) -> Self { self.scale = scale.max(1); self }
+    pub fn margin(mut self, cells: u16) -> Self { self.margin = cells; self }
+}
+
+/// Glifo 8x8 de `ch` (fila a fila, bit 0 = píxel izquierdo). Los caracteres
+/// que la fuente no cubre se dibujan como `?`.
+fn glyph(ch: char) -> [u8; 8] {
+    font8x8::BASIC_FONTS.get(ch)
+        .or_else(|| font8x8::BOX_FONTS.get(ch))
+        .or_else(|| font8x8::BLOCK_FONTS.get(ch))
+        .or_else(|| font8x8::LATIN_FONTS.get(ch))
+        .or_else(|| font8x8::GREEK_FONTS.get(ch))
+        .or_else(|| font8x8::MISC_FONTS.get(ch))
+        .unwrap_or_else(|| font8x8::BASIC_FONTS.get('?').unwrap_or_default())
+}
+
+/// Mezcla `a` con `b` (`t` = peso de `a`).
+fn blend(a: Rgb8, b: Rgb8, t: f32) -> Rgb8 {
+    let mix = |x: u8, y: u8| (x as f32 * t + y as f32 * (1.0 - t)).round() as u8;
+    (mix(a.0, b.0), mix(a.1, b.1), mix(a.2, b.2))
+}
+
+/// Imagen RGB de 8 bits por canal.
+struct Canvas {
+    width: usize,
+    height: usize,
+    pixels: Vec<u8>,
+}
+
+impl Canvas {
+    fn fill_rect(&mut self, x: usize, y: usize, w: usize, h: usize, color: Rgb8) {
+        for py in y..(y + h).min(self.height) {
+            for px in x..(x + w).min(self.width) {
+                let i = (py * self.width + px) * 3;
+                self.pixels[i..i + 3].copy_from_slice(&[color.0, color.1, color.2]);
+            }
+        }
+    }
+}
+
+/// Pinta `grid` con el tamaño de celda y margen de `options`.
+fn rasterize(grid: &Grid, options: &RasterOptions) -> Canvas {
+    let scale = options.scale.max(1) as usize;
+    let margin = options.margin as usize;
+    let (cell_w, cell_h) = (CELL_WIDTH * scale, CELL_HEIGHT * scale);
+    let width = (grid.width() as usize + 2 * margin) * cell_w;
+    let height = (grid.height() as usize + 2 * margin) * cell_h;
+    let mut canvas = Canvas { width, height, pixels: Vec::new() };
+    canvas.pixels = [DEFAULT_BACKGROUND.0, DEFAULT_BACKGROUND.1, DEFAULT_BACKGROUND.2].repeat(width * height);
+
+    for y in 0..grid.height() {
+        for x in 0..grid.width() {
+            let cell = grid.cell(x, y);
+            if cell.is_continuation() { continue; }
+            let wide = x + 1 < grid.width() && grid.cell(x + 1, y).is_continuation();
+            let columns = if wide { 2 } else { 1 };
+            let (left, top) = ((x as usize + margin) * cell_w, (y as usize + margin) * cell_h);
+            let style = &cell.style;
+            let (mut fg, bg) = cell_colors(style);
+            let bg = bg.unwrap_or(DEFAULT_BACKGROUND);
+            canvas.fill_rect(left, top, cell_w * columns, cell_h, bg);
+            if style.has(Attribute::Hidden) { continue; }
+            if style.has(Attribute::Dim) { fg = blend(fg, bg, 0.6); }
+
+            let bitmap = glyph(cell.ch);
+            // Negrita simulada: el glifo se repite desplazado un píxel.
+            let offsets: &[usize] = if style.has(Attribute::Bold) { &[0, 1] } else { &[0] };
+            // Cursiva simulada: las filas superiores se desplazan a la derecha.
+            let italic = style.has(Attribute::Italic);
+            for (row, bits) in bitmap.iter().enumerate() {
+                let slant = if italic { (7 - row) / 3 } else { 0 };
+                for col in 0..8 {
+                    if bits & (1 << col) == 0 { continue; }
+                    for offset in offsets {
+                        // Sin salirse de la celda: el fondo de la siguiente lo taparía.
+                        let px = left + (col + slant + offset).min(CELL_WIDTH * columns - 1) * scale;
+                        // Cada fila de la fuente ocupa dos filas de la celda.
+                        canvas.fill_rect(px, top + row * 2 * scale, scale, 2 * scale, fg);
+                    }
+                }
+            }
+            let line = |canvas: &mut Canvas, row: usize, color: Rgb8| {
+                canvas.fill_rect(left, top + row * scale, cell_w * columns, scale, color);
+            };
+            let underline_color = style.underline_color.map(|c| c.to_rgb()).unwrap_or(fg);
+            if style.has(Attribute::Underline) || style.has(Attribute::CurlyUnderline) {
+                line(&mut canvas, CELL_HEIGHT - 1, underline_color);
+            }
+            if style.has(Attribute::DoubleUnderline) {
+                line(&mut canvas, CELL_HEIGHT - 1, underline_color);
+                line(&mut canvas, CELL_HEIGHT - 3, underline_color);
+            }
+            if style.has(Attribute::Strikethrough) {
+                line(&mut canvas, CELL_HEIGHT / 2, fg);
+            }
+        }
+    }
+    canvas
+}
+
+fn encoding_error(e: impl std::error::Error + Send + Sync + 'static) -> BannerError {
+    BannerError::Io(io::Error::other(e))
+}
+
+/// Escribe `grid` como imagen PNG.
+pub(crate) fn write_png(grid: &Grid, options: &RasterOptions, writer: impl Write) -> Result<(), BannerError> {
+    let canvas = rasterize(grid, options);
+    let mut encoder = png::Encoder::new(writer, canvas.width as u32, canvas.height as u32);
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+    let mut png = encoder.write_header().map_err(encoding_error)?;
+    png.write_image_data(&canvas.pixels).map_err(encoding_error)?;
+    png.finish().map_err(encoding_error)?;
+    Ok(())
+}
+
+/// Escribe `composition` como GIF animado. `iterations` es el número de
+/// reproducciones (`None` = bucle infinito). Los frames idénticos consecutivos
+/// se fusionan sumando sus duraciones.
+pub(crate) fn write_gif(
+    composition: &Composition,
+    iterations: Option<usize>,
+    options: &RasterOptions,
+    writer: impl Write,
+) -> Result<(), BannerError> {
+    let mut frames: Vec<(&Grid, Duration)> = Vec::new();
+    for (grid, duration) in &composition.frames {
+        match frames.last_mut() {
+            Some((last, total)) if *last == grid => *total += *duration,
+            _ => frames.push((grid, *duration)),
+        }
+    }
+
+    let first = rasterize(frames[0].0, options);
+    let too_large = || BannerError::InvalidAnimation("La imagen excede el tamaño máximo de un GIF (65535 px)".to_string());
+    let width = u16::try_from(first.width).map_err(|_| too_large())?;
+    let height = u16::try_from(first.height).map_err(|_| too_large())?;
+    let mut encoder = gif::Encoder::new(writer, width, height, &[]).map_err(encoding_error)?;
+    let repeat = match iterations {
+        None => gif::Repeat::Infinite,
+        Some(n) => gif::Repeat::Finite(n.saturating_sub(1).min(u16::MAX as usize) as u16),
+    };
+    encoder.set_repeat(repeat).map_err(encoding_error)?;
+
+    for (index, (grid, duration)) in frames.iter().enumerate() {
+        let canvas = if index == 0 { None } else { Some(rasterize(grid, options)) };
+        let pixels = &canvas.as_ref().unwrap_or(&first).pixels;
+        let mut frame = gif::Frame::from_rgb_speed(width, height, pixels, 10);
+        // El GIF mide los retardos en centésimas de segundo.
+        frame.delay = (duration.as_millis() / 10).min(u16::MAX as u128) as u16;
+        encoder.write_frame(&frame).map_err(encoding_error)?;
+    }
+    Ok(())
+}
+
+/// Compone y escribe una animación como GIF: las repeticiones finitas forman
+/// una sola línea de tiempo para que `hold_last` afecte solo al final.
+pub(crate) fn write_animation_gif(
+    animation: &Animation,
+    render: &RenderOptions,
+    options: &RasterOptions,
+    writer: impl Write,
+) -> Result<(), BannerError> {
+    match animation.repeat {
+        Some(passes) => write_gif(&super::compose(animation, render, passes.max(1))?, Some(1), options, writer),
+        None => write_gif(&super::compose(animation, render, 1)?, None, options, writer),
+    }
+}
+
+impl Animation {
+    /// Exporta la animación como GIF animado con los retardos de cada frame
+    /// (sin estilo; para colores, bordes y alineación usa `Banner::to_gif`).
+    pub fn to_gif(&self, options: &RasterOptions, writer: impl Write) -> Result<(), BannerError> {
+        let style = crate::style::Style::default();
+        write_animation_gif(self, &super::plain_options(&style), options, writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::banner::Banner;
+    use crate::style::{rgb, Style};
+
+    #[test]
+    fn test_rasterize_paints_glyph_and_background() {
+        let mut grid = Grid::new(2, 1);
+        grid.put_ansi(0, 0, "\x1b[38;2;255;0;0;48;2;0;0;255m|");
+        let canvas = rasterize(&grid, &RasterOptions::new().scale(1).margin(0));
+        assert_eq!((canvas.width, canvas.height), (16, 16));
+        let pixel = |x: usize, y: usize| &canvas.pixels[(y * canvas.width + x) * 3..][..3];
+        // '|' ocupa las columnas 3-4 del glifo; la columna 0 es fondo.
+        assert_eq!(pixel(3, 4), &[255, 0, 0]);
+        assert_eq!(pixel(0, 4), &[0, 0, 255]);
+        assert_eq!(pixel(8, 4), &[DEFAULT_BACKGROUND.0, DEFAULT_BACKGROUND.1, DEFAULT_BACKGROUND.2]);
+    }
+
+    #[test]
+    fn test_bold_italic_glyphs_stay_inside_their_cell() {
+        let mut grid = Grid::new(2, 1);
+        grid.put_ansi(0, 0, "\x1b[1;3;38;2;255;0;0m▕\x1b[0;48;2;0;0;255m ");
+        let canvas = rasterize(&grid, &RasterOptions::new().scale(1).margin(0));
+        let pixel = |x: usize, y: usize| &canvas.pixels[(y * canvas.width + x) * 3..][..3];
+        // '▕' solo ocupa la columna 7: en la fila superior, cursiva y negrita la
+        // llevarían a las columnas 9-10. Se queda en la 7 y la siguiente celda
+        // conserva su fondo.
+        assert_eq!(pixel(7, 0), &[255, 0, 0]);
+        assert!((0..16).all(|y| pixel(8, y) == [0, 0, 255]));
+    }
+
+    #[test]
+    fn test_png_and_gif_signatures() {
+        let mut banner = Banner::from_animation(Animation::new(vec![vec!["a".to_string()], vec!["b".to_string()]], 120))
+            .with_style(Style::new().fg(rgb(255, 200, 0)));
+        let mut png = Vec::new();
+        banner.to_png(&RasterOptions::new(), &mut png).unwrap();
+        assert!(png.starts_with(b"\x89PNG\r\n\x1a\n"));
+
+        let mut gif = Vec::new();
+        banner.to_gif(&RasterOptions::new(), &mut gif).unwrap();
+        assert!(gif.starts_with(b"GIF89a"));
+        // Una extensión de control por frame, con un retardo de 12 centésimas.
+        let controls: Vec<usize> = gif.windows(3).enumerate().filter(|(_, w)| *w == [0x21, 0xf9, 0x04]).map(|(i, _)| i).collect();
+        assert_eq!(controls.len(), 2);
+        assert!(controls.iter().all(|&i| gif[i + 4] == 12 && gif[i + 5] == 0));
+    }
+}
@@ -0,0 +1,202 @@
+// --- src/export/svg.rs ---
+//! Exportación a SVG: una rejilla de texto monoespaciado. Las animaciones se
+//! convierten en un grupo por frame cuya visibilidad controlan keyframes CSS.
+
+use super::{cell_colors, escape_xml, hex, row_runs, Composition, DEFAULT_BACKGROUND};
+use crate::animation::Animation;
+use crate::error::BannerError;
+use crate::grid::Grid;
+use crate::renderer::RenderOptions;
+use crate::style::Attribute;
+use std::fmt::Write as _;
+use std::io::Write;
+use std::time::Duration;
+
+const FONT_SIZE: f32 = 14.0;
+/// Ancho de celda: 0.6em, el avance habitual de las fuentes monoespaciadas.
+const CELL_WIDTH: f32 = FONT_SIZE * 0.6;
+const LINE_HEIGHT: f32 = FONT_SIZE * 1.2;
+/// Margen alrededor de la rejilla.
+const MARGIN: f32 = 10.0;
+
+/// Escribe `composition` como documento SVG. `iterations` es el número de
+/// repeticiones de la animación CSS (`None` = infinita); con un solo frame no
+/// se genera animación.
+pub(crate) fn write_svg(composition: &Composition, iterations: Option<usize>, mut writer: impl Write) -> Result<(), BannerError> {
+    let width = composition.width as f32 * CELL_WIDTH + 2.0 * MARGIN;
+    let height = composition.height as f32 * LINE_HEIGHT + 2.0 * MARGIN;
+    let animated = composition.frames.len() > 1;
+    let mut out = String::new();
+    let _ = writeln!(
+        out,
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{w:.1}\" height=\"{h:.1}\" viewBox=\"0 0 {w:.1} {h:.1}\">",
+        w = width,
+        h = height
+    );
+    out.push_str("<style>\n");
+    let _ = writeln!(
+        out,
+        "text {{ font-family: ui-monospace, 'DejaVu Sans Mono', Menlo, Consolas, monospace; font-size: {}px; white-space: pre; }}",
+        FONT_SIZE
+    );
+    if animated {
+        write_keyframes(&mut out, &composition.frames, iterations);
+    }
+    out.push_str("</style>\n");
+    let _ = writeln!(out, "<rect width=\"100%\" height=\"100%\" fill=\"{}\"/>", hex(DEFAULT_BACKGROUND));
+
+    for (index, (grid, _)) in composition.frames.iter().enumerate() {
+        if animated {
+            let _ = writeln!(out, "<g class=\"f{}\">", index);
+        }
+        write_grid(&mut out, grid);
+        if animated {
+            out.push_str("</g>\n");
+        }
+    }
+    out.push_str("</svg>\n");
+    writer.write_all(out.as_bytes())?;
+    writer.flush()?;
+    Ok(())
+}
+
+/// Un keyframe por frame: visible solo en su tramo de la línea de tiempo.
+fn write_keyframes(out: &mut String, frames: &[(Grid, Duration)], iterations: Option<usize>) {
+    let total: f64 = frames.iter().map(|(_, d)| d.as_secs_f64()).sum::<f64>().max(0.001);
+    let count = iterations.map_or("infinite".to_string(), |n| n.max(1).to_string());
+    let _ = writeln!(
+        out,
+        "g {{ visibility: hidden; animation-duration: {:.3}s; animation-timing-function: step-end; animation-iteration-count: {}; animation-fill-mode: forwards; }}",
+        total, count
+    );
+    let mut start = 0.0;
+    for (index, (_, duration)) in frames.iter().enumerate() {
+        let end = start + duration.as_secs_f64();
+        let is_last = index + 1 == frames.len();
+        let _ = write!(out, "@keyframes k{} {{", index);
+        if index == 0 {
+            out.push_str(" 0% { visibility: visible; }");
+        } else {
+            let _ = write!(out, " 0% {{ visibility: hidden; }} {:.4}% {{ visibility: visible; }}", start / total * 100.0);
+        }
+        if !is_last {
+            let _ = write!(out, " {:.4}% {{ visibility: hidden; }}", end / total * 100.0);
+        }
+        // El último frame queda visible al terminar (fill-mode forwards).
+        let _ = writeln!(out, " 100% {{ visibility: {}; }} }}", if is_last { "visible" } else { "hidden" });
+        let _ = writeln!(out, ".f{} {{ animation-name: k{}; }}", index, index);
+        start = end;
+    }
+}
+
+/// Vuelca una rejilla: primero los fondos de cada tramo y luego el texto.
+fn write_grid(out: &mut String, grid: &Grid) {
+    for y in 0..grid.height() {
+        let top = MARGIN + y as f32 * LINE_HEIGHT;
+        for run in row_runs(grid, y) {
+            let (fg, bg) = cell_colors(run.style);
+            let x = MARGIN + run.column as f32 * CELL_WIDTH;
+            let length = run.columns as f32 * CELL_WIDTH;
+            if let Some(bg) = bg {
+                // Un poco más ancho para que no queden juntas visibles entre tramos.
+                let _ = writeln!(
+                    out,
+                    "<rect x=\"{:.1}\" y=\"{:.1}\" width=\"{:.1}\" height=\"{:.1}\" fill=\"{}\"/>",
+                    x, top, length + 0.5, LINE_HEIGHT + 0.5, hex(bg)
+                );
+            }
+            let style = run.style;
+            let decorated = style.has(Attribute::Underline)
+                || style.has(Attribute::DoubleUnderline)
+                || style.has(Attribute::CurlyUnderline)
+                || style.has(Attribute::Strikethrough);
+            if style.has(Attribute::Hidden) || (run.text.trim().is_empty() && !decorated) {
+                continue;
+            }
+
+            let mut attrs = format!("fill=\"{}\"", hex(fg));
+            if style.has(Attribute::Bold) { attrs.push_str(" font-weight=\"bold\""); }
+            if style.has(Attribute::Italic) { attrs.push_str(" font-style=\"italic\""); }
+            if style.has(Attribute::Dim) { attrs.push_str(" fill-opacity=\"0.6\""); }
+            let mut decoration = Vec::new();
+            if style.has(Attribute::Underline) || style.has(Attribute::DoubleUnderline) || style.has(Attribute::CurlyUnderline) {
+                decoration.push("underline");
+            }
+            if style.has(Attribute::Strikethrough) { decoration.push("line-through"); }
+            if !decoration.is_empty() {
+                let mut css = format!("text-decoration: {}", decoration.join(" "));
+                if style.has(Attribute::DoubleUnderline) { css.push_str(" double"); }
+                if style.has(Attribute::CurlyUnderline) { css.push_str(" wavy"); }
+                if let Some(color) = style.underline_color { let _ = write!(css, " {}", hex(color.to_rgb())); }
+                let _ = write!(attrs, " style=\"{}\"", css);
+            }
+
+            // `textLength` fija el ancho del tramo a la rejilla sea cual sea la fuente.
+            let text = format!(
+                "<text x=\"{:.1}\" y=\"{:.1}\" textLength=\"{:.1}\" lengthAdjust=\"spacingAndGlyphs\" {}>{}</text>",
+                x, top + FONT_SIZE, length, attrs, escape_xml(&run.text)
+            );
+            match &style.link {
+                Some(url) => { let _ = writeln!(out, "<a href=\"{}\">{}</a>", escape_xml(url), text); }
+                None => { let _ = writeln!(out, "{}", text); }
+            }
+        }
+    }
+}
+
+impl Animation {
+    /// Exporta la animación como SVG animado con keyframes CSS (o estático si
+    /// solo tiene un frame). Respeta `repeat`, las duraciones por frame y
+    /// `hold_last`. Como `to_asciicast`, los frames van sin estilo; para
+    /// exportar con estilo, borde y alineación usa `Banner::to_svg`.
+    pub fn to_svg(&self, writer: impl Write) -> Result<(), BannerError> {
+        let style = crate::style::Style::default();
+        write_animation(self, &super::plain_options(&style), writer)
+    }
+}
+
+/// Compone y escribe una animación: las repeticiones finitas se exportan
+/// como una sola línea de tiempo para que `hold_last` afecte solo al final.
+pub(crate) fn write_animation(animation: &Animation, options: &RenderOptions, writer: impl Write) -> Result<(), BannerError> {
+    match animation.repeat {
+        Some(passes) => write_svg(&super::compose(animation, options, passes.max(1))?, Some(1), writer),
+        None => write_svg(&super::compose(animation, options, 1)?, None, writer),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::banner::Banner;
+    use crate::style::{rgb, Style};
+
+    #[test]
+    fn test_static_banner_svg() {
+        let animation = Animation::new(vec![vec!["a<b".to_string()]], 100);
+        let mut banner = Banner::from_animation(animation)
+            .with_style(Style::new().fg(rgb(255, 0, 0)).bg(rgb(0, 0, 255)).bold())
+            .padding_top(0);
+        let mut svg = Vec::new();
+        banner.to_svg(&mut svg).unwrap();
+        let svg = String::from_utf8(svg).unwrap();
+        assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\""));
+        assert!(svg.contains("fill=\"#0000ff\""));
+        assert!(svg.contains("fill=\"#ff0000\" font-weight=\"bold\">a&lt;b</text>"));
+        assert!(!svg.contains("@keyframes"));
+    }
+
+    #[test]
+    fn test_animation_svg_uses_keyframes() {
+        let animation = Animation::new(vec![vec!["a".to_string()], vec!["b".to_string()]], 250)
+            .with_frame_duration(1, std::time::Duration::from_millis(750))
+            .repeat(None);
+        let mut svg = Vec::new();
+        animation.to_svg(&mut svg).unwrap();
+        let svg = String::from_utf8(svg).unwrap();
+        assert!(svg.contains("animation-duration: 1.000s"));
+        assert!(svg.contains("animation-iteration-count: infinite"));
+        assert!(svg.contains("@keyframes k1 { 0% { visibility: hidden; } 25.0000% { visibility: visible; } 100% { visibility: visible; } }"));
+        assert!(svg.contains("@keyframes k0 { 0% { visibility: visible; } 25.0000% { visibility: hidden; } 100% { visibility: hidden; } }"));
+        assert_eq!(svg.matches("<g class=").count(), 2);
+    }
+}
@@ -0,0 +1,389 @@
+// --- src/generators.rs ---
+//! Generadores procedurales (lluvia digital, campo de estrellas, fuegos
+//! artificiales, confeti) que producen una `Animation` a partir de una semilla,
+//! con la opción de descubrir un banner FIGlet al final.
+
+use crate::animation::{Animation, Frame};
+use crate::banner::{figlet_lines, BannerContent};
+use crate::error::BannerError;
+use crate::fonts::{load_font, FontSource};
+use crate::utils::Rng;
+use std::time::Duration;
+
+/// Caracteres de la lluvia digital (katakana de media anchura, dígitos y símbolos).
+const RAIN_GLYPHS: &[char] = &[
+    'ｱ', 'ｲ', 'ｳ', 'ｴ', 'ｵ', 'ｶ', 'ｷ', 'ｸ', 'ｹ', 'ｺ', 'ｻ', 'ｼ', 'ｽ', 'ｾ', 'ｿ', 'ﾀ', 'ﾁ', 'ﾂ', 'ﾃ', 'ﾄ',
+    '0', '1', '2', '3', '4', '5', '7', '8', '9', ':', '=', '*', '+', '<', '>', '|',
+];
+/// Estrellas de la más lejana a la más cercana.
+const STAR_GLYPHS: &[char] = &['.', '·', '+', '*'];
+/// Chispas de un fuego artificial, de recién lanzada a apagándose.
+const SPARK_GLYPHS: &[char] = &['*', '+', '.'];
+const CONFETTI_GLYPHS: &[char] = &['*', 'o', '~', '+', '%', '#', '&', '@', 'x', '^'];
+
+/// Efecto que produce un `Generator`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GeneratorKind {
+    /// Columnas de caracteres que caen con una estela.
+    MatrixRain,
+    /// Estrellas que se acercan desde el centro de la pantalla.
+    Starfield,
+    /// Cohetes que suben y estallan en chispas que caen por la gravedad.
+    Fireworks,
+    /// Papelitos que caen meciéndose de lado a lado.
+    Confetti,
+}
+
+/// Cómo termina la animación: contenido ya renderizado o texto FIGlet.
+#[derive(Debug, Clone)]
+enum Finale {
+    Content(BannerContent),
+    Figlet { text: String, font: FontSource },
+}
+
+/// Generador reproducible de animaciones procedurales.
+///
+/// La misma semilla produce siempre los mismos frames. Con `reveal_text` o
+/// `reveal_content`, el último tercio de la animación descubre el banner
+/// centrado mientras el efecto se desvanece.
+///
+/// ```no_run
+/// use vornix_banner::{Banner, BuiltinFont, FontSource, Generator};
+///
+/// let animation = Generator::matrix_rain()
+///     .area(70, 16)
+///     .seed(42)
+///     .reveal_text("Vornix", FontSource::Builtin(BuiltinFont::Standard))
+///     .animation()
+///     .unwrap();
+/// Banner::from_animation(animation).display().unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct Generator {
+    kind: GeneratorKind,
+    area: (u16, u16),
+    frames: usize,
+    frame_delay: Duration,
+    seed: u64,
+    density: f32,
+    finale: Option<Finale>,
+}
+
+impl Generator {
+    /// Generador con valores por defecto: 80x20, 90 frames a 50 ms y densidad 0.5.
+    pub fn new(kind: GeneratorKind) -> Self {
+        Self {
+            kind,
+            area: (80, 20),
+            frames: 90,
+            frame_delay: Duration::from_millis(50),
+            seed: 0x5EED,
+            density: 0.5,
+            finale: None,
+        }
+    }
+
+    pub fn matrix_rain() -> Self { Self::new(GeneratorKind::MatrixRain) }
+    pub fn starfield() -> Self { Self::new(GeneratorKind::Starfield) }
+    pub fn fireworks() -> Self { Self::new(GeneratorKind::Fireworks) }
+    pub fn confetti() -> Self { Self::new(GeneratorKind::Confetti) }
+
+    /// Tamaño de la escena en columnas y filas.
+    pub fn area(mut self, width: u16, height: u16) -> Self { self.area = (width, height); self }
+    /// Número de frames a generar (al menos 1).
+    pub fn frames(mut self, frames: usize) -> Self { self.frames = frames.max(1); self }
+    pub fn delay(mut self, duration: Duration) -> Self { self.frame_delay = duration; self }
+    pub fn seed(mut self, seed: u64) -> Self { self.seed = seed; self }
+    /// Cantidad de partículas, de `0.0` (casi ninguna) a `1.0` (escena llena).
+    pub fn density(mut self, density: f32) -> Self { self.density = density.clamp(0.0, 1.0); self }
+
+    /// Termina descubriendo `text` renderizado con la fuente FIGlet indicada.
+    pub fn reveal_text(mut self, text: &str, font: FontSource) -> Self {
+        self.finale = Some(Finale::Figlet { text: text.to_string(), font });
+        self
+    }
+
+    /// Termina descubriendo un contenido ya renderizado (líneas de texto plano).
+    pub fn reveal_content(mut self, content: BannerContent) -> Self {
+        self.finale = Some(Finale::Content(content));
+        self
+    }
+
+    /// Genera la animación (una sola pasada). Solo falla si no se puede
+    /// cargar la fuente o generar el texto de `reveal_text`.
+    pub fn animation(&self) -> Result<Animation, BannerError> {
+        let finale = match &self.finale {
+            Some(Finale::Content(content)) => Some(content.clone()),
+            Some(Finale::Figlet { text, font }) => {
+                Some(figlet_lines(&load_font(font)?, text)?)
+            }
+            None => None,
+        };
+
+        let (width, height) = self.area;
+        let mut rng = Rng::new(self.seed);
+        let mut scene = Scene::new(self.kind, width as f32, height as f32, self.density, &mut rng);
+        // El banner aparece en el último tercio, celda a celda en orden aleatorio.
+        let reveal_start = self.frames - self.frames / 3;
+        let thresholds: Vec<f32> = (0..width as usize * height as usize).map(|_| rng.next_f32()).collect();
+        let banner = finale.map(|content| centered(&content, width, height));
+
+        let mut frames = Vec::with_capacity(self.frames);
+        for index in 0..self.frames {
+            let mut canvas = Canvas::new(width, height);
+            scene.step(&mut canvas, &mut rng);
+            if let Some(banner) = &banner {
+                let progress = if index + 1 == self.frames {
+                    1.0
+                } else {
+                    (index + 1).saturating_sub(reveal_start) as f32 / (self.frames - reveal_start).max(1) as f32
+                };
+                canvas.reveal(banner, progress, &thresholds);
+            }
+            frames.push(canvas.into_frame());
+        }
+        Ok(Animation::new(frames, self.frame_delay.as_millis() as u64).delay(self.frame_delay))
+    }
+}
+
+/// Rejilla de caracteres sobre la que se dibuja cada frame.
+struct Canvas {
+    width: usize,
+    cells: Vec<Vec<char>>,
+}
+
+impl Canvas {
+    fn new(width: u16, height: u16) -> Self {
+        Self { width: width as usize, cells: vec![vec![' '; width as usize]; height as usize] }
+    }
+
+    /// Dibuja `ch` en la celda más cercana a `(x, y)`, si está dentro.
+    fn plot(&mut self, x: f32, y: f32, ch: char) {
+        let (x, y) = (x.round(), y.round());
+        if x >= 0.0 && y >= 0.0 && (x as usize) < self.width && (y as usize) < self.cells.len() {
+            self.cells[y as usize][x as usize] = ch;
+        }
+    }
+
+    /// Con `progress` (0.0-1.0) creciente, el resto de la escena se apaga y
+    /// las celdas del banner aparecen. Con `1.0` solo queda el banner.
+    fn reveal(&mut self, banner: &[Vec<char>], progress: f32, thresholds: &[f32]) {
+        if progress <= 0.0 {
+            return;
+        }
+        for (y, row) in self.cells.iter_mut().enumerate() {
+            for (x, cell) in row.iter_mut().enumerate() {
+                if thresholds[y * self.width + x] < progress {
+                    *cell = banner[y][x];
+                }
+            }
+        }
+    }
+
+    fn into_frame(self) -> Frame {
+        self.cells.into_iter().map(|row| row.into_iter().collect()).collect()
+    }
+}
+
+/// Coloca `content` centrado en una rejilla `width`x`height` (recortando lo que sobre).
+fn centered(content: &[String], width: u16, height: u16) -> Vec<Vec<char>> {
+    let (width, height) = (width as usize, height as usize);
+    let content_width = content.iter().map(|l| l.chars().count()).max().unwrap_or(0);
+    let left = width.saturating_sub(content_width) / 2;
+    let top = height.saturating_sub(content.len()) / 2;
+    let mut grid = vec![vec![' '; width]; height];
+    for (y, line) in content.iter().enumerate().filter(|(y, _)| top + y < height) {
+        for (x, ch) in line.chars().enumerate().filter(|(x, _)| left + x < width) {
+            grid[top + y][left + x] = ch;
+        }
+    }
+    grid
+}
+
+/// Partícula genérica: posición, velocidad, vida restante y carácter.
+#[derive(Debug, Clone)]
+struct Particle {
+    x: f32,
+    y: f32,
+    vx: f32,
+    vy: f32,
+    life: u32,
+    ch: char,
+}
+
+/// Estado de cada efecto entre frames.
+enum Scene {
+    /// Por columna: fila de la cabeza, velocidad y longitud de la estela.
+    Rain { drops: Vec<(f32, f32, f32)>, height: f32 },
+    /// Estrellas en coordenadas (x, y, profundidad) con x e y en [-1, 1].
+    Stars { stars: Vec<(f32, f32, f32)>, width: f32, height: f32 },
+    Fireworks { sparks: Vec<Particle>, width: f32, height: f32, launch_chance: f32 },
+    Confetti { pieces: Vec<Particle>, width: f32, height: f32 },
+}
+
+impl Scene {
+    fn new(kind: GeneratorKind, width: f32, height: f32, density: f32, rng: &mut Rng) -> Self {
+        let count = |max: f32| ((max * density).round() as usize).max(1);
+        match kind {
+            GeneratorKind::MatrixRain => {
+                // Solo una parte de las columnas tiene gota, según la densidad.
+                let drops = (0..width as usize)
+                    .map(|_| {
+                        if rng.next_f32() < 0.2 + 0.8 * density {
+                            (-rng.next_f32() * height, 0.3 + rng.next_f32() * 0.9, 3.0 + rng.next_f32() * height / 2.0)
+                        } else {
+                            (f32::INFINITY, 0.0, 0.0)
+                        }
+                    })
+                    .collect();
+                Scene::Rain { drops, height }
+            }
+            GeneratorKind::Starfield => {
+                let stars = (0..count(width * height / 8.0)).map(|_| {
+                    let depth = rng.next_f32();
+                    random_star(rng, depth)
+                }).collect();
+                Scene::Stars { stars, width, height }
+            }
+            GeneratorKind::Fireworks => Scene::Fireworks { sparks: Vec::new(), width, height, launch_chance: 0.05 + 0.25 * density },
+            GeneratorKind::Confetti => {
+                let pieces = (0..count(width * height / 6.0)).map(|_| {
+                    let y = rng.next_f32() * height;
+                    confetti_piece(rng, width, y)
+                }).collect();
+                Scene::Confetti { pieces, width, height }
+            }
+        }
+    }
+
+    /// Avanza la simulación un frame y la dibuja en `canvas`.
+    fn step(&mut self, canvas: &mut Canvas, rng: &mut Rng) {
+        match self {
+            Scene::Rain { drops, height } => {
+                for (x, (head, speed, length)) in drops.iter_mut().enumerate() {
+                    if !head.is_finite() { continue; }
+                    *head += *speed;
+                    if *head - *length > *height {
+                        *head = -rng.next_f32() * *height / 2.0;
+                    }
+                    let tail = (*head - *length).max(0.0) as usize;
+                    for y in tail..=head.max(0.0) as usize {
+                        canvas.plot(x as f32, y as f32, pick(rng, RAIN_GLYPHS));
+                    }
+                }
+            }
+            Scene::Stars { stars, width, height } => {
+                let (cx, cy) = (*width / 2.0, *height / 2.0);
+                for star in stars.iter_mut() {
+                    star.2 -= 0.02;
+                    let (sx, sy) = (cx + star.0 / star.2 * cx, cy + star.1 / star.2 * cy);
+                    if star.2 <= 0.05 || !(0.0..*width).contains(&sx) || !(0.0..*height).contains(&sy) {
+                        *star = random_star(rng, 1.0);
+                        continue;
+                    }
+                    let glyph = ((1.0 - star.2) * STAR_GLYPHS.len() as f32) as usize;
+                    canvas.plot(sx, sy, STAR_GLYPHS[glyph.min(STAR_GLYPHS.len() - 1)]);
+                }
+            }
+            Scene::Fireworks { sparks, width, height, launch_chance } => {
+                if rng.next_f32() < *launch_chance {
+                    // Un cohete: sube hasta agotar su vida y entonces estalla.
+                    let x = *width * (0.15 + 0.7 * rng.next_f32());
+                    let rise = *height * (0.35 + 0.4 * rng.next_f32());
+                    sparks.push(Particle { x, y: *height - 1.0, vx: 0.0, vy: -1.0, life: rise as u32, ch: '|' });
+                }
+                let mut born = Vec::new();
+                for spark in sparks.iter_mut() {
+                    spark.x += spark.vx;
+                    spark.y += spark.vy;
+                    spark.life = spark.life.saturating_sub(1);
+                    if spark.ch == '|' {
+                        if spark.life == 0 {
+                            born.extend(burst(rng, spark.x, spark.y));
+                        }
+                    } else {
+                        spark.vy += 0.06; // Gravedad
+                        spark.ch = SPARK_GLYPHS[(3 - spark.life.min(12) / 4).min(2) as usize];
+                    }
+                }
+                sparks.retain(|s| s.life > 0);
+                sparks.extend(born);
+                for spark in sparks.iter() {
+                    canvas.plot(spark.x, spark.y, spark.ch);
+                }
+            }
+            Scene::Confetti { pieces, width, height } => {
+                for piece in pieces.iter_mut() {
+                    piece.life += 1;
+                    piece.x = (piece.x + piece.vx * (piece.life as f32 * 0.3).sin()).rem_euclid(*width);
+                    piece.y += piece.vy;
+                    if piece.y >= *height {
+                        *piece = confetti_piece(rng, *width, 0.0);
+                    }
+                    canvas.plot(piece.x, piece.y, piece.ch);
+                }
+            }
+        }
+    }
+}
+
+fn pick(rng: &mut Rng, glyphs: &[char]) -> char {
+    glyphs[rng.below(glyphs.len())]
+}
+
+/// Estrella en una posición aleatoria con profundidad hasta `max_depth`.
+fn random_star(rng: &mut Rng, max_depth: f32) -> (f32, f32, f32) {
+    (rng.next_f32() * 2.0 - 1.0, rng.next_f32() * 2.0 - 1.0, 0.1 + max_depth * rng.next_f32())
+}
+
+/// Chispas que salen en círculo desde `(x, y)`.
+fn burst(rng: &mut Rng, x: f32, y: f32) -> Vec<Particle> {
+    let count = 10 + rng.below(8);
+    (0..count)
+        .map(|i| {
+            let angle = i as f32 / count as f32 * std::f32::consts::TAU;
+            let speed = 0.6 + rng.next_f32() * 0.6;
+            // Las celdas son el doble de altas que de anchas.
+            Particle { x, y, vx: angle.cos() * speed * 1.6, vy: angle.sin() * speed * 0.8, life: 8 + rng.below(8) as u32, ch: '*' }
+        })
+        .collect()
+}
+
+fn confetti_piece(rng: &mut Rng, width: f32, y: f32) -> Particle {
+    Particle {
+        x: rng.next_f32() * width,
+        y,
+        vx: rng.next_f32() * 0.8,
+        vy: 0.2 + rng.next_f32() * 0.5,
+        life: rng.below(20) as u32,
+        ch: pick(rng, CONFETTI_GLYPHS),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generators_are_seeded_and_rectangular() {
+        for kind in [GeneratorKind::MatrixRain, GeneratorKind::Starfield, GeneratorKind::Fireworks, GeneratorKind::Confetti] {
+            let generator = Generator::new(kind).area(30, 10).frames(40);
+            let a = generator.animation().unwrap();
+            assert_eq!(a.frames.len(), 40);
+            assert!(a.validate().is_ok(), "{:?}", kind);
+            assert_eq!(a.frames, generator.animation().unwrap().frames, "{:?}", kind);
+            assert_ne!(a.frames, generator.clone().seed(7).animation().unwrap().frames, "{:?}", kind);
+        }
+    }
+
+    #[test]
+    fn test_reveal_ends_with_centered_banner() {
+        let animation = Generator::confetti()
+            .area(8, 3)
+            .frames(12)
+            .reveal_content(vec!["hi".to_string()])
+            .animation()
+            .unwrap();
+        assert_eq!(animation.frames.last().unwrap(), &vec!["        ", "   hi   ", "        "]);
+    }
+}
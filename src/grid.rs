@@ -0,0 +1,683 @@
+// --- src/grid.rs ---
+//! Búfer de celdas de terminal. Las líneas ya estilizadas (con secuencias SGR y
+//! OSC 8) se interpretan y se vuelcan a una rejilla de celdas, lo que permite
+//! comparar frames y emitir solo los cambios. `Screen` añade un cursor y
+//! reproduce salida cruda de terminal (movimientos, borrados) sobre la rejilla.
+
+use crate::style::Attribute;
+use std::fmt::Write as _;
+use std::sync::Arc;
+use unicode_width::UnicodeWidthChar;
+
+/// Color de una celda tal y como llega en la secuencia SGR.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum CellColor {
+    /// Índice de paleta: 0-15 colores ANSI, 16-255 paleta xterm.
+    Indexed(u8),
+    Rgb(u8, u8, u8),
+}
+
+impl CellColor {
+    /// Valor RGB del color (los índices se resuelven con la paleta xterm).
+    pub(crate) fn to_rgb(self) -> (u8, u8, u8) {
+        match self {
+            CellColor::Indexed(i) => crate::style::xterm_to_rgb(i),
+            CellColor::Rgb(r, g, b) => (r, g, b),
+        }
+    }
+
+    /// Escribe los parámetros SGR del color. `base` es 30 (texto), 40 (fondo) o 50 (subrayado).
+    fn write_sgr(self, base: u8, out: &mut String) {
+        match self {
+            CellColor::Indexed(i) if i < 8 && base != 50 => { let _ = write!(out, ";{}", base + i); }
+            CellColor::Indexed(i) if i < 16 && base != 50 => { let _ = write!(out, ";{}", base + 60 + i - 8); }
+            CellColor::Indexed(i) => { let _ = write!(out, ";{};5;{}", base + 8, i); }
+            CellColor::Rgb(r, g, b) => { let _ = write!(out, ";{};2;{};{};{}", base + 8, r, g, b); }
+        }
+    }
+}
+
+/// Estilo completo de una celda.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct CellStyle {
+    pub fg: Option<CellColor>,
+    pub bg: Option<CellColor>,
+    /// Máscara de bits de `Attribute` (ver `attribute_bit`).
+    pub attributes: u16,
+    pub underline_color: Option<CellColor>,
+    /// Destino del hipervínculo OSC 8, si la celda forma parte de uno.
+    pub link: Option<Arc<str>>,
+}
+
+/// Bit que representa cada atributo dentro de `CellStyle::attributes`.
+pub(crate) fn attribute_bit(attribute: Attribute) -> u16 {
+    1 << match attribute {
+        Attribute::Bold => 0,
+        Attribute::Dim => 1,
+        Attribute::Italic => 2,
+        Attribute::Underline => 3,
+        Attribute::DoubleUnderline => 4,
+        Attribute::CurlyUnderline => 5,
+        Attribute::Blink => 6,
+        Attribute::Reverse => 7,
+        Attribute::Hidden => 8,
+        Attribute::Strikethrough => 9,
+    }
+}
+
+const UNDERLINES: u16 = (1 << 3) | (1 << 4) | (1 << 5);
+
+impl CellStyle {
+    pub(crate) fn has(&self, attribute: Attribute) -> bool {
+        self.attributes & attribute_bit(attribute) != 0
+    }
+
+    /// Secuencia SGR completa (empezando por un reset) que produce este estilo.
+    pub(crate) fn sgr(&self) -> String {
+        let mut out = String::from("\x1b[0");
+        let codes = [
+            (Attribute::Bold, "1"), (Attribute::Dim, "2"), (Attribute::Italic, "3"),
+            (Attribute::Underline, "4"), (Attribute::DoubleUnderline, "4:2"), (Attribute::CurlyUnderline, "4:3"),
+            (Attribute::Blink, "5"), (Attribute::Reverse, "7"), (Attribute::Hidden, "8"),
+            (Attribute::Strikethrough, "9"),
+        ];
+        for (attribute, code) in codes {
+            if self.has(attribute) {
+                out.push(';');
+                out.push_str(code);
+            }
+        }
+        if let Some(fg) = self.fg { fg.write_sgr(30, &mut out); }
+        if let Some(bg) = self.bg { bg.write_sgr(40, &mut out); }
+        if let Some(ul) = self.underline_color { ul.write_sgr(50, &mut out); }
+        out.push('m');
+        out
+    }
+
+    /// Aplica una lista de parámetros SGR (ya separados por `;`).
+    pub(crate) fn apply_sgr(&mut self, params: &str) {
+        let mut parts = params.split(';').peekable();
+        if params.is_empty() {
+            *self = CellStyle { link: self.link.take(), ..Default::default() };
+            return;
+        }
+        while let Some(part) = parts.next() {
+            // Subparámetros con ':' (p. ej. 4:3 o 38:2::r:g:b).
+            let mut sub = part.split(':');
+            let code: u16 = sub.next().and_then(|c| c.parse().ok()).unwrap_or(0);
+            let set = |s: &mut Self, a: Attribute, on: bool| {
+                if on { s.attributes |= attribute_bit(a) } else { s.attributes &= !attribute_bit(a) }
+            };
+            match code {
+                0 => *self = CellStyle { link: self.link.take(), ..Default::default() },
+                1 => set(self, Attribute::Bold, true),
+                2 => set(self, Attribute::Dim, true),
+                3 => set(self, Attribute::Italic, true),
+                4 => {
+                    self.attributes &= !UNDERLINES;
+                    match sub.next() {
+                        Some("0") => {}
+                        Some("2") => set(self, Attribute::DoubleUnderline, true),
+                        Some("3") | Some("4") | Some("5") => set(self, Attribute::CurlyUnderline, true),
+                        _ => set(self, Attribute::Underline, true),
+                    }
+                }
+                5 | 6 => set(self, Attribute::Blink, true),
+                7 => set(self, Attribute::Reverse, true),
+                8 => set(self, Attribute::Hidden, true),
+                9 => set(self, Attribute::Strikethrough, true),
+                21 => { self.attributes &= !UNDERLINES; set(self, Attribute::DoubleUnderline, true) }
+                22 => { set(self, Attribute::Bold, false); set(self, Attribute::Dim, false) }
+                23 => set(self, Attribute::Italic, false),
+                24 => self.attributes &= !UNDERLINES,
+                25 => set(self, Attribute::Blink, false),
+                27 => set(self, Attribute::Reverse, false),
+                28 => set(self, Attribute::Hidden, false),
+                29 => set(self, Attribute::Strikethrough, false),
+                30..=37 => self.fg = Some(CellColor::Indexed((code - 30) as u8)),
+                90..=97 => self.fg = Some(CellColor::Indexed((code - 90 + 8) as u8)),
+                40..=47 => self.bg = Some(CellColor::Indexed((code - 40) as u8)),
+                100..=107 => self.bg = Some(CellColor::Indexed((code - 100 + 8) as u8)),
+                39 => self.fg = None,
+                49 => self.bg = None,
+                59 => self.underline_color = None,
+                38 | 48 | 58 => {
+                    let sub: Vec<&str> = sub.collect();
+                    let color = if sub.is_empty() {
+                        extended_color(&mut parts)
+                    } else {
+                        // Forma con ':' — el espacio de color (tras "2:") puede ir vacío.
+                        let nums: Vec<u8> = sub.iter().filter(|s| !s.is_empty()).filter_map(|s| s.parse().ok()).collect();
+                        match (sub.first().copied(), nums.as_slice()) {
+                            (Some("5"), [5, i]) => Some(CellColor::Indexed(*i)),
+                            (Some("2"), [2, .., r, g, b]) => Some(CellColor::Rgb(*r, *g, *b)),
+                            _ => None,
+                        }
+                    };
+                    match code {
+                        38 => self.fg = color,
+                        48 => self.bg = color,
+                        _ => self.underline_color = color,
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Lee el resto de un color extendido separado por `;` (`5;n` o `2;r;g;b`).
+fn extended_color<'a>(parts: &mut impl Iterator<Item = &'a str>) -> Option<CellColor> {
+    let mut next = || parts.next().and_then(|p| p.parse::<u8>().ok());
+    match next()? {
+        5 => Some(CellColor::Indexed(next()?)),
+        2 => Some(CellColor::Rgb(next()?, next()?, next()?)),
+        _ => None,
+    }
+}
+
+/// Una celda de la terminal. Los caracteres anchos ocupan dos celdas: la
+/// segunda queda marcada como continuación (`ch == '\0'`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Cell {
+    pub ch: char,
+    pub style: CellStyle,
+}
+
+impl Default for Cell {
+    fn default() -> Self {
+        Self { ch: ' ', style: CellStyle::default() }
+    }
+}
+
+impl Cell {
+    pub(crate) fn is_continuation(&self) -> bool {
+        self.ch == '\0'
+    }
+}
+
+/// Rejilla rectangular de celdas.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Grid {
+    width: u16,
+    height: u16,
+    cells: Vec<Cell>,
+}
+
+impl Grid {
+    /// Crea una rejilla vacía (espacios sin estilo).
+    pub(crate) fn new(width: u16, height: u16) -> Self {
+        Self { width, height, cells: vec![Cell::default(); width as usize * height as usize] }
+    }
+
+    pub(crate) fn width(&self) -> u16 {
+        self.width
+    }
+
+    pub(crate) fn height(&self) -> u16 {
+        self.height
+    }
+
+    pub(crate) fn cell(&self, x: u16, y: u16) -> &Cell {
+        &self.cells[y as usize * self.width as usize + x as usize]
+    }
+
+    fn set(&mut self, x: u16, y: u16, cell: Cell) {
+        if x < self.width && y < self.height {
+            let index = y as usize * self.width as usize + x as usize;
+            self.cells[index] = cell;
+        }
+    }
+
+    /// Escribe un texto con secuencias SGR/OSC 8 a partir de (`x`, `y`).
+    /// Lo que exceda la rejilla se recorta.
+    pub(crate) fn put_ansi(&mut self, x: u16, y: u16, text: &str) {
+        let mut style = CellStyle::default();
+        let mut column = x as usize;
+        let mut chars = text.chars().peekable();
+        while let Some(ch) = chars.next() {
+            if ch == '\x1b' {
+                match chars.peek() {
+                    Some('[') => {
+                        chars.next();
+                        let mut params = String::new();
+                        for c in chars.by_ref() {
+                            if c.is_ascii_alphabetic() || c == '~' {
+                                if c == 'm' { style.apply_sgr(&params); }
+                                break;
+                            }
+                            params.push(c);
+                        }
+                    }
+                    Some(']') => {
+                        chars.next();
+                        let mut body = String::new();
+                        while let Some(c) = chars.next() {
+                            if c == '\x07' { break; }
+                            if c == '\x1b' { chars.next(); break; }
+                            body.push(c);
+                        }
+                        if let Some(rest) = body.strip_prefix("8;") {
+                            let url = rest.split_once(';').map(|(_, url)| url).unwrap_or_default();
+                            style.link = if url.is_empty() { None } else { Some(Arc::from(url)) };
+                        }
+                    }
+                    _ => {}
+                }
+                continue;
+            }
+            let width = ch.width().unwrap_or(0);
+            if width == 0 {
+                continue;
+            }
+            if column > u16::MAX as usize {
+                break;
+            }
+            self.set(column as u16, y, Cell { ch, style: style.clone() });
+            if width == 2
+                && let Some(next) = (column as u16).checked_add(1)
+            {
+                self.set(next, y, Cell { ch: '\0', style: style.clone() });
+            }
+            column += width;
+        }
+    }
+
+    /// Vuelve a serializar la fila `y` como texto con secuencias SGR/OSC 8,
+    /// sin los espacios finales que no tienen estilo.
+    #[cfg(feature = "asciicast")]
+    pub(crate) fn row_to_ansi(&self, y: u16) -> String {
+        let blank = Cell::default();
+        let end = (0..self.width).rev().find(|&x| *self.cell(x, y) != blank).map_or(0, |x| x + 1);
+        let mut out = String::new();
+        let mut writer = StyleWriter::default();
+        for x in 0..end {
+            let cell = self.cell(x, y);
+            if cell.is_continuation() { continue; }
+            writer.transition(&cell.style, &mut out);
+            out.push(cell.ch);
+        }
+        writer.finish(&mut out);
+        out
+    }
+
+    /// Rellena las celdas `[start, end)` (índices lineales) con `cell`.
+    #[cfg(feature = "asciicast")]
+    fn fill(&mut self, start: usize, end: usize, cell: &Cell) {
+        let end = end.min(self.cells.len());
+        if start < end {
+            self.cells[start..end].fill(cell.clone());
+        }
+    }
+
+    /// Desplaza las filas `top..=bottom` `n` posiciones hacia arriba (o hacia
+    /// abajo si `up` es falso), rellenando con `blank` las que quedan libres.
+    #[cfg(feature = "asciicast")]
+    fn scroll(&mut self, top: u16, bottom: u16, n: u16, up: bool, blank: &Cell) {
+        let w = self.width as usize;
+        let (top, bottom) = (top as usize, bottom as usize);
+        if w == 0 || top > bottom || bottom >= self.height as usize {
+            return;
+        }
+        let n = (n as usize).min(bottom - top + 1);
+        let region = &mut self.cells[top * w..(bottom + 1) * w];
+        if up {
+            region.rotate_left(n * w);
+            let len = region.len();
+            region[len - n * w..].fill(blank.clone());
+        } else {
+            region.rotate_right(n * w);
+            region[..n * w].fill(blank.clone());
+        }
+    }
+
+    /// Genera las secuencias necesarias para pasar de `previous` a `self`,
+    /// emitiendo solo las celdas que han cambiado. Si las dimensiones no
+    /// coinciden se redibuja todo. La rejilla se sitúa en la fila `origin_row`
+    /// de la pantalla.
+    pub(crate) fn diff(&self, previous: &Grid, origin_row: u16) -> String {
+        let full = self.width != previous.width || self.height != previous.height;
+        let mut out = String::new();
+        let mut writer = StyleWriter::default();
+        let mut cursor: Option<(u16, u16)> = None;
+        for y in 0..self.height {
+            for x in 0..self.width {
+                let cell = self.cell(x, y);
+                if cell.is_continuation() { continue; }
+                let wide = x + 1 < self.width && self.cell(x + 1, y).is_continuation();
+                let changed = full
+                    || cell != previous.cell(x, y)
+                    || (wide && self.cell(x + 1, y) != previous.cell(x + 1, y));
+                if !changed { continue; }
+                if cursor != Some((x, y)) {
+                    let _ = write!(out, "\x1b[{};{}H", origin_row as u32 + y as u32 + 1, x + 1);
+                }
+                writer.transition(&cell.style, &mut out);
+                out.push(cell.ch);
+                cursor = Some((x + if wide { 2 } else { 1 }, y));
+            }
+        }
+        writer.finish(&mut out);
+        out
+    }
+}
+
+/// Estado del intérprete de secuencias de escape de `Screen`. Se conserva
+/// entre llamadas a `feed`, por lo que una secuencia puede llegar partida.
+#[cfg(feature = "asciicast")]
+#[derive(Debug, Clone)]
+enum ParserState {
+    Ground,
+    Escape,
+    /// Designación de juego de caracteres (`ESC (`...): se ignora el siguiente carácter.
+    Charset,
+    Csi(String),
+    Osc(String),
+    /// `ESC` dentro de un OSC: normalmente el inicio del terminador `ESC \`.
+    OscEscape(String),
+}
+
+/// Pantalla virtual: interpreta la salida cruda de un programa (texto, saltos
+/// de línea, movimientos del cursor, borrados y SGR) sobre una `Grid`. Solo
+/// la usa el lector de grabaciones asciicast.
+#[cfg(feature = "asciicast")]
+#[derive(Debug, Clone)]
+pub(crate) struct Screen {
+    grid: Grid,
+    x: u16,
+    y: u16,
+    style: CellStyle,
+    saved: (u16, u16),
+    state: ParserState,
+}
+
+#[cfg(feature = "asciicast")]
+impl Screen {
+    pub(crate) fn new(width: u16, height: u16) -> Self {
+        Self {
+            grid: Grid::new(width, height),
+            x: 0,
+            y: 0,
+            style: CellStyle::default(),
+            saved: (0, 0),
+            state: ParserState::Ground,
+        }
+    }
+
+    /// Contenido actual, una línea con estilos por fila.
+    pub(crate) fn lines(&self) -> Vec<String> {
+        (0..self.grid.height).map(|y| self.grid.row_to_ansi(y)).collect()
+    }
+
+    /// Celda en blanco con el fondo actual (lo que dejan los borrados).
+    fn blank(&self) -> Cell {
+        Cell { ch: ' ', style: CellStyle { bg: self.style.bg, ..Default::default() } }
+    }
+
+    fn index(&self, x: u16, y: u16) -> usize {
+        y as usize * self.grid.width as usize + x as usize
+    }
+
+    fn line_feed(&mut self) {
+        if self.y + 1 >= self.grid.height {
+            let blank = self.blank();
+            self.grid.scroll(0, self.grid.height.saturating_sub(1), 1, true, &blank);
+        } else {
+            self.y += 1;
+        }
+    }
+
+    fn print(&mut self, ch: char) {
+        let width = ch.width().unwrap_or(0) as u16;
+        if width == 0 || self.grid.width == 0 {
+            return;
+        }
+        // Ajuste automático de línea al escribir pasada la última columna.
+        if self.x + width > self.grid.width {
+            self.x = 0;
+            self.line_feed();
+        }
+        let style = self.style.clone();
+        self.grid.set(self.x, self.y, Cell { ch, style: style.clone() });
+        if width == 2 {
+            self.grid.set(self.x + 1, self.y, Cell { ch: '\0', style });
+        }
+        self.x += width;
+    }
+
+    /// Interpreta un fragmento de salida.
+    pub(crate) fn feed(&mut self, data: &str) {
+        for ch in data.chars() {
+            let state = std::mem::replace(&mut self.state, ParserState::Ground);
+            self.state = match state {
+                ParserState::Ground => {
+                    self.control_or_print(ch);
+                    if ch == '\x1b' { ParserState::Escape } else { ParserState::Ground }
+                }
+                ParserState::Escape => self.escape(ch),
+                ParserState::Charset => ParserState::Ground,
+                ParserState::Csi(mut params) => {
+                    if ('\x40'..='\x7e').contains(&ch) {
+                        self.csi(&params, ch);
+                        ParserState::Ground
+                    } else {
+                        params.push(ch);
+                        ParserState::Csi(params)
+                    }
+                }
+                ParserState::Osc(mut body) => match ch {
+                    '\x07' => { self.osc(&body); ParserState::Ground }
+                    '\x1b' => ParserState::OscEscape(body),
+                    _ => { body.push(ch); ParserState::Osc(body) }
+                },
+                ParserState::OscEscape(body) => {
+                    self.osc(&body);
+                    ParserState::Ground
+                }
+            };
+        }
+    }
+
+    fn control_or_print(&mut self, ch: char) {
+        match ch {
+            '\x1b' | '\x07' => {}
+            '\r' => self.x = 0,
+            '\n' | '\x0b' | '\x0c' => self.line_feed(),
+            '\x08' => self.x = self.x.saturating_sub(1),
+            '\t' => self.x = ((self.x / 8 + 1) * 8).min(self.grid.width.saturating_sub(1)),
+            c if c.is_control() => {}
+            c => self.print(c),
+        }
+    }
+
+    fn escape(&mut self, ch: char) -> ParserState {
+        match ch {
+            '[' => return ParserState::Csi(String::new()),
+            ']' => return ParserState::Osc(String::new()),
+            '(' | ')' | '*' | '+' => return ParserState::Charset,
+            '7' => self.saved = (self.x, self.y),
+            '8' => (self.x, self.y) = self.saved,
+            'D' => self.line_feed(),
+            'E' => { self.x = 0; self.line_feed(); }
+            'M' => {
+                if self.y == 0 {
+                    let blank = self.blank();
+                    self.grid.scroll(0, self.grid.height.saturating_sub(1), 1, false, &blank);
+                } else {
+                    self.y -= 1;
+                }
+            }
+            'c' => *self = Screen::new(self.grid.width, self.grid.height),
+            _ => {}
+        }
+        ParserState::Ground
+    }
+
+    fn osc(&mut self, body: &str) {
+        if let Some(rest) = body.strip_prefix("8;") {
+            let url = rest.split_once(';').map(|(_, url)| url).unwrap_or_default();
+            self.style.link = if url.is_empty() { None } else { Some(Arc::from(url)) };
+        }
+    }
+
+    fn csi(&mut self, params: &str, action: char) {
+        // Modos privados (`?25l`, `>c`...) no afectan al contenido.
+        if params.starts_with(['?', '>', '<', '=']) {
+            return;
+        }
+        if action == 'm' {
+            self.style.apply_sgr(params);
+            return;
+        }
+        let args: Vec<u16> = params.split(';').map(|p| p.parse().unwrap_or(0)).collect();
+        let arg = |i: usize, default: u16| args.get(i).copied().filter(|&n| n != 0).unwrap_or(default);
+        let (w, h) = (self.grid.width, self.grid.height);
+        let max_x = w.saturating_sub(1);
+        let max_y = h.saturating_sub(1);
+        let n = arg(0, 1);
+        match action {
+            'H' | 'f' => {
+                self.y = (arg(0, 1) - 1).min(max_y);
+                self.x = (arg(1, 1) - 1).min(max_x);
+            }
+            'A' => self.y = self.y.saturating_sub(n),
+            'B' | 'e' => self.y = (self.y.saturating_add(n)).min(max_y),
+            'C' | 'a' => self.x = (self.x.saturating_add(n)).min(max_x),
+            'D' => self.x = self.x.saturating_sub(n).min(max_x),
+            'E' => { self.x = 0; self.y = (self.y.saturating_add(n)).min(max_y); }
+            'F' => { self.x = 0; self.y = self.y.saturating_sub(n); }
+            'G' | '`' => self.x = (n - 1).min(max_x),
+            'd' => self.y = (n - 1).min(max_y),
+            's' => self.saved = (self.x, self.y),
+            'u' => (self.x, self.y) = self.saved,
+            'J' | 'K' => {
+                let x = self.x.min(max_x);
+                let (line_start, line_end) = (self.index(0, self.y), self.index(0, self.y) + w as usize);
+                let cursor = self.index(x, self.y);
+                let (start, end) = match (action, args.first().copied().unwrap_or(0)) {
+                    ('J', 0) => (cursor, usize::MAX),
+                    ('J', 1) => (0, cursor + 1),
+                    ('J', _) => (0, usize::MAX),
+                    (_, 0) => (cursor, line_end),
+                    (_, 1) => (line_start, cursor + 1),
+                    _ => (line_start, line_end),
+                };
+                let blank = self.blank();
+                self.grid.fill(start, end, &blank);
+            }
+            'X' => {
+                let start = self.index(self.x.min(max_x), self.y);
+                let end = (start + n as usize).min(self.index(0, self.y) + w as usize);
+                let blank = self.blank();
+                self.grid.fill(start, end, &blank);
+            }
+            'P' | '@' => {
+                // Borrar o insertar caracteres desplaza el resto de la línea.
+                let start = self.index(self.x.min(max_x), self.y);
+                let end = self.index(0, self.y) + w as usize;
+                let count = (n as usize).min(end - start);
+                let blank = self.blank();
+                let line = &mut self.grid.cells[start..end];
+                if action == 'P' {
+                    line.rotate_left(count);
+                    let len = line.len();
+                    line[len - count..].fill(blank);
+                } else {
+                    line.rotate_right(count);
+                    line[..count].fill(blank);
+                }
+            }
+            'L' | 'M' => {
+                let blank = self.blank();
+                self.grid.scroll(self.y, max_y, n, action == 'M', &blank);
+            }
+            'S' | 'T' => {
+                let blank = self.blank();
+                self.grid.scroll(0, max_y, n, action == 'S', &blank);
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Emite las transiciones de estilo e hipervínculo entre celdas consecutivas.
+#[derive(Default)]
+struct StyleWriter {
+    current: CellStyle,
+}
+
+impl StyleWriter {
+    fn transition(&mut self, next: &CellStyle, out: &mut String) {
+        if next.link != self.current.link {
+            let _ = write!(out, "\x1b]8;;{}\x1b\\", next.link.as_deref().unwrap_or(""));
+        }
+        let visual = |s: &CellStyle| (s.fg, s.bg, s.attributes, s.underline_color);
+        if visual(next) != visual(&self.current) {
+            out.push_str(&next.sgr());
+        }
+        self.current = next.clone();
+    }
+
+    fn finish(&mut self, out: &mut String) {
+        self.transition(&CellStyle::default(), out);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_sgr_and_links() {
+        let mut grid = Grid::new(6, 1);
+        grid.put_ansi(0, 0, "\x1b[1;38;2;10;20;30mab\x1b[0m\x1b]8;;https://x\x1b\\\x1b[4:3;91mc\x1b]8;;\x1b\\d");
+        let a = grid.cell(0, 0);
+        assert_eq!(a.ch, 'a');
+        assert!(a.style.has(Attribute::Bold));
+        assert_eq!(a.style.fg, Some(CellColor::Rgb(10, 20, 30)));
+        let c = grid.cell(2, 0);
+        assert!(c.style.has(Attribute::CurlyUnderline));
+        assert_eq!(c.style.fg, Some(CellColor::Indexed(9)));
+        assert_eq!(c.style.link.as_deref(), Some("https://x"));
+        assert_eq!(grid.cell(3, 0).style.link, None);
+    }
+
+    #[test]
+    fn test_wide_char_at_last_column_does_not_overflow() {
+        let mut grid = Grid::new(2, 1);
+        grid.put_ansi(u16::MAX, 0, "漢");
+        grid.put_ansi(1, 0, "漢");
+        assert_eq!(grid.cell(1, 0).ch, '漢');
+    }
+
+    #[cfg(feature = "asciicast")]
+    #[test]
+    fn test_screen_replays_cursor_moves_and_erases() {
+        let mut screen = Screen::new(6, 3);
+        screen.feed("hello\r\nworld\x1b[1;2H\x1b[31mA\x1b[0m\x1b[2;3H\x1b[K");
+        assert_eq!(screen.lines(), vec!["h\x1b[0;31mA\x1b[0mllo".to_string(), "wo".to_string(), String::new()]);
+        // Las secuencias pueden llegar partidas entre fragmentos.
+        screen.feed("\x1b[");
+        screen.feed("2J");
+        assert!(screen.lines().iter().all(String::is_empty));
+    }
+
+    #[cfg(feature = "asciicast")]
+    #[test]
+    fn test_screen_scrolls_at_bottom() {
+        let mut screen = Screen::new(3, 2);
+        screen.feed("a\r\nb\r\nc");
+        assert_eq!(screen.lines(), vec!["b".to_string(), "c".to_string()]);
+    }
+
+    #[test]
+    fn test_diff_only_emits_changed_cells() {
+        let mut before = Grid::new(5, 2);
+        before.put_ansi(0, 0, "hello");
+        let mut after = before.clone();
+        after.put_ansi(4, 1, "!");
+        assert_eq!(after.diff(&before, 0), "\x1b[2;5H!");
+        assert_eq!(after.diff(&before, 10), "\x1b[12;5H!");
+        assert_eq!(after.diff(&after, 0), "");
+    }
+}
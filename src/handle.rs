@@ -0,0 +1,145 @@
+// --- src/handle.rs ---
+//! Reproducción de banners en segundo plano: `Banner::display_in_background()`
+//! devuelve un `AnimationHandle` para pausar, reanudar o detener la animación
+//! mientras la aplicación sigue trabajando.
+
+use crate::banner::Banner;
+use crate::error::BannerError;
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
+
+/// Estado compartido entre el hilo que anima y quien lo controla. El
+/// renderizador lo consulta entre frames y durante las esperas.
+#[derive(Debug, Default)]
+pub(crate) struct PlaybackControl {
+    state: Mutex<PlaybackState>,
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+struct PlaybackState {
+    paused: bool,
+    stopped: bool,
+}
+
+impl PlaybackControl {
+    fn update(&self, f: impl FnOnce(&mut PlaybackState)) {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        f(&mut state);
+    }
+
+    fn snapshot(&self) -> PlaybackState {
+        *self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    pub(crate) fn stop(&self) {
+        self.update(|s| s.stopped = true);
+    }
+
+    pub(crate) fn is_stopped(&self) -> bool {
+        self.snapshot().stopped
+    }
+
+    pub(crate) fn is_paused(&self) -> bool {
+        let state = self.snapshot();
+        state.paused && !state.stopped
+    }
+}
+
+/// Control de un banner que se está mostrando en un hilo aparte.
+///
+/// Al soltar el handle la animación se detiene y se espera a que la terminal
+/// quede restaurada.
+///
+/// ```no_run
+/// use vornix_banner::{Animation, Banner, ScreenMode};
+///
+/// let spinner = Animation::new(vec![vec!["|".into()], vec!["/".into()], vec!["-".into()]], 100).repeat(None);
+/// let handle = Banner::from_animation(spinner)
+///     .screen_mode(ScreenMode::Inline)
+///     .display_in_background();
+/// // ... cargar configuración ...
+/// handle.stop();
+/// handle.join().unwrap();
+/// ```
+#[derive(Debug)]
+pub struct AnimationHandle {
+    control: Arc<PlaybackControl>,
+    thread: Option<JoinHandle<Result<(), BannerError>>>,
+}
+
+impl AnimationHandle {
+    pub(crate) fn spawn(mut banner: Banner) -> Self {
+        let control = Arc::new(PlaybackControl::default());
+        let thread_control = Arc::clone(&control);
+        let thread = std::thread::Builder::new()
+            .name("vornix-banner".to_string())
+            .spawn(move || banner.display_controlled(&thread_control))
+            .expect("no se pudo crear el hilo de animación");
+        Self { control, thread: Some(thread) }
+    }
+
+    /// Pide que la animación termine en cuanto sea posible.
+    pub fn stop(&self) {
+        self.control.stop();
+    }
+
+    /// Congela la animación en el frame actual.
+    pub fn pause(&self) {
+        self.control.update(|s| s.paused = true);
+    }
+
+    /// Reanuda una animación pausada.
+    pub fn resume(&self) {
+        self.control.update(|s| s.paused = false);
+    }
+
+    /// Indica si el hilo de animación ya terminó.
+    pub fn is_finished(&self) -> bool {
+        self.thread.as_ref().is_none_or(|t| t.is_finished())
+    }
+
+    /// Espera a que la animación termine (por sí sola, por `stop()` o porque el
+    /// usuario pulsó una tecla de salida) y devuelve su resultado.
+    ///
+    /// Con repeticiones infinitas solo retorna tras `stop()` o una tecla de salida.
+    pub fn join(mut self) -> Result<(), BannerError> {
+        self.join_inner()
+    }
+
+    fn join_inner(&mut self) -> Result<(), BannerError> {
+        match self.thread.take() {
+            Some(thread) => thread
+                .join()
+                .unwrap_or_else(|_| Err(BannerError::Internal("El hilo de animación terminó con un panic".to_string()))),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Drop for AnimationHandle {
+    /// Detiene la animación y espera a que se restaure la terminal.
+    fn drop(&mut self) {
+        if self.thread.is_some() {
+            self.stop();
+            if let Err(e) = self.join_inner() {
+                eprintln!("[term_banner] Advertencia: la animación en segundo plano terminó con error: {}", e);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_control_state_transitions() {
+        let control = PlaybackControl::default();
+        assert!(!control.is_paused() && !control.is_stopped());
+        control.update(|s| s.paused = true);
+        assert!(control.is_paused());
+        control.stop();
+        // Una animación detenida deja de considerarse pausada para poder salir.
+        assert!(!control.is_paused() && control.is_stopped());
+    }
+}
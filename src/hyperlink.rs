@@ -0,0 +1,99 @@
+// --- src/hyperlink.rs ---
+//! Hipervínculos de terminal (OSC 8): permiten que el banner, o zonas concretas
+//! de los frames de una animación, sean clicables en terminales compatibles.
+
+use std::ops::Range;
+
+/// Zona rectangular de un frame que enlaza a una URL.
+///
+/// Las filas y columnas son relativas al contenido del frame (antes de aplicar
+/// bordes o centrado). Las columnas se miden en celdas de terminal.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HyperlinkRegion {
+    pub url: String,
+    pub rows: Range<usize>,
+    pub columns: Range<usize>,
+}
+
+impl HyperlinkRegion {
+    /// Crea una zona enlazada. Los caracteres de control de `url` se descartan.
+    pub fn new(url: &str, rows: Range<usize>, columns: Range<usize>) -> Self {
+        Self { url: sanitize_url(url), rows, columns }
+    }
+}
+
+/// Quita de `url` los caracteres de control (C0, DEL y C1): un `ESC`, `BEL` o
+/// `ST` cerraría la secuencia OSC 8 antes de tiempo y lo que siguiera llegaría
+/// a la terminal como secuencias de escape.
+pub(crate) fn sanitize_url(url: &str) -> String {
+    url.chars().filter(|c| !c.is_control()).collect()
+}
+
+/// Envuelve `text` en la secuencia OSC 8 que lo enlaza con `url`. La URL se
+/// vuelve a limpiar porque `HyperlinkRegion::url` es un campo público.
+pub(crate) fn wrap(text: &str, url: &str) -> String {
+    format!("\x1b]8;;{}\x1b\\{}\x1b]8;;\x1b\\", sanitize_url(url), text)
+}
+
+/// Trozo de una línea: columna inicial, texto y URL (si está dentro de una zona enlazada).
+pub(crate) type Segment<'a> = (usize, String, Option<&'a str>);
+
+/// Divide `line` (fila `row`) en trozos según las zonas enlazadas, desplazadas
+/// `offset` (filas, columnas) por la decoración del banner.
+pub(crate) fn segments<'a>(
+    line: &str,
+    row: usize,
+    regions: &'a [HyperlinkRegion],
+    offset: (usize, usize),
+) -> Vec<Segment<'a>> {
+    let url_at = |column: usize| {
+        regions
+            .iter()
+            .find(|r| {
+                r.rows.contains(&row.wrapping_sub(offset.0)) && r.columns.contains(&column.wrapping_sub(offset.1))
+            })
+            .map(|r| r.url.as_str())
+    };
+
+    let mut result: Vec<Segment<'a>> = Vec::new();
+    let mut column = 0;
+    for ch in line.chars() {
+        let url = url_at(column);
+        match result.last_mut() {
+            Some((_, text, current)) if *current == url => text.push(ch),
+            _ => result.push((column, ch.to_string(), url)),
+        }
+        column += unicode_width::UnicodeWidthChar::width(ch).unwrap_or(0);
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::get_line_width_accurate;
+
+    #[test]
+    fn test_wrapped_link_has_no_visible_width() {
+        let linked = wrap("docs", "https://example.com/docs?a=1&b=2");
+        assert!(linked.starts_with("\x1b]8;;https://example.com/docs?a=1&b=2\x1b\\"));
+        assert_eq!(get_line_width_accurate(&linked), 4);
+    }
+
+    #[test]
+    fn test_control_characters_cannot_escape_the_link() {
+        let url = "https://x\x1b]8;;\x07\x1b[2J\u{9c}/y";
+        assert_eq!(HyperlinkRegion::new(url, 0..1, 0..1).url, "https://x]8;;[2J/y");
+        assert_eq!(wrap("a", url), "\x1b]8;;https://x]8;;[2J/y\x1b\\a\x1b]8;;\x1b\\");
+    }
+
+    #[test]
+    fn test_segments_follow_region_columns() {
+        let regions = [HyperlinkRegion::new("https://a", 0..1, 2..4)];
+        let parts = segments("abcdef", 0, &regions, (0, 0));
+        let expected: Vec<Segment> =
+            vec![(0, "ab".into(), None), (2, "cd".into(), Some("https://a")), (4, "ef".into(), None)];
+        assert_eq!(parts, expected);
+        assert_eq!(segments("abcdef", 1, &regions, (0, 0)).len(), 1);
+    }
+}
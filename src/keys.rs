@@ -0,0 +1,133 @@
+// --- src/keys.rs ---
+//! Controles de teclado durante la reproducción de animaciones.
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+
+/// Función de usuario asociada a una tecla.
+pub type KeyCallback = Arc<dyn Fn(KeyEvent) + Send + Sync>;
+
+/// Acción que desencadena una tecla mientras se reproduce una animación.
+#[derive(Clone)]
+pub enum KeyAction {
+    /// Termina la animación y restaura la terminal.
+    Quit,
+    /// Pausa o reanuda.
+    TogglePause,
+    /// Pausa y avanza un frame.
+    StepForward,
+    /// Pausa y retrocede un frame.
+    StepBackward,
+    /// Vuelve al primer frame de la primera repetición.
+    Restart,
+    /// Duplica la velocidad (hasta 8x).
+    SpeedUp,
+    /// Reduce la velocidad a la mitad (hasta 1/8x).
+    SlowDown,
+    /// Salta al último frame de la última repetición.
+    SkipToEnd,
+    /// Llama a una función de usuario; la animación continúa.
+    Custom(KeyCallback),
+}
+
+impl fmt::Debug for KeyAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            KeyAction::Quit => f.write_str("Quit"),
+            KeyAction::TogglePause => f.write_str("TogglePause"),
+            KeyAction::StepForward => f.write_str("StepForward"),
+            KeyAction::StepBackward => f.write_str("StepBackward"),
+            KeyAction::Restart => f.write_str("Restart"),
+            KeyAction::SpeedUp => f.write_str("SpeedUp"),
+            KeyAction::SlowDown => f.write_str("SlowDown"),
+            KeyAction::SkipToEnd => f.write_str("SkipToEnd"),
+            KeyAction::Custom(_) => f.write_str("Custom(..)"),
+        }
+    }
+}
+
+/// Mapa de teclas a acciones. Las teclas sin asignar se ignoran.
+///
+/// Por defecto: Esc, `q` y Ctrl+C salen; espacio pausa; ←/→ retroceden o
+/// avanzan un frame; `r` reinicia; `+`/`-` cambian la velocidad; Fin salta al
+/// final.
+///
+/// ```no_run
+/// use vornix_banner::{Animation, KeyAction, KeyBindings, KeyCode, KeyModifiers};
+///
+/// let bindings = KeyBindings::default()
+///     .unbind(KeyCode::Char('q'), KeyModifiers::NONE)
+///     .on_key(KeyCode::Char('s'), |_| eprintln!("captura pedida"));
+/// let animation = Animation::new(vec![vec!["hola".into()]], 100).with_key_bindings(bindings);
+/// ```
+#[derive(Debug, Clone)]
+pub struct KeyBindings {
+    map: HashMap<(KeyCode, KeyModifiers), KeyAction>,
+}
+
+impl Default for KeyBindings {
+    fn default() -> Self {
+        let none = KeyModifiers::NONE;
+        Self::empty()
+            .bind(KeyCode::Esc, none, KeyAction::Quit)
+            .bind(KeyCode::Char('q'), none, KeyAction::Quit)
+            .bind(KeyCode::Char('c'), KeyModifiers::CONTROL, KeyAction::Quit)
+            .bind(KeyCode::Char(' '), none, KeyAction::TogglePause)
+            .bind(KeyCode::Right, none, KeyAction::StepForward)
+            .bind(KeyCode::Left, none, KeyAction::StepBackward)
+            .bind(KeyCode::Char('r'), none, KeyAction::Restart)
+            .bind(KeyCode::Char('+'), none, KeyAction::SpeedUp)
+            .bind(KeyCode::Char('-'), none, KeyAction::SlowDown)
+            .bind(KeyCode::End, none, KeyAction::SkipToEnd)
+    }
+}
+
+impl KeyBindings {
+    /// Mapa sin ninguna tecla (ni siquiera para salir).
+    pub fn empty() -> Self {
+        Self { map: HashMap::new() }
+    }
+
+    /// Asigna `action` a la tecla `code` con `modifiers`, sustituyendo la anterior.
+    pub fn bind(mut self, code: KeyCode, modifiers: KeyModifiers, action: KeyAction) -> Self {
+        self.map.insert((code, modifiers), action); self
+    }
+
+    /// Quita la asignación de una tecla.
+    pub fn unbind(mut self, code: KeyCode, modifiers: KeyModifiers) -> Self {
+        self.map.remove(&(code, modifiers)); self
+    }
+
+    /// Atajo para asignar una función de usuario a una tecla sin modificadores.
+    pub fn on_key(self, code: KeyCode, callback: impl Fn(KeyEvent) + Send + Sync + 'static) -> Self {
+        self.bind(code, KeyModifiers::NONE, KeyAction::Custom(Arc::new(callback)))
+    }
+
+    /// Acción de un evento de teclado. En los caracteres se ignora Mayús, que
+    /// ya se refleja en el propio carácter (`+` suele llegar con Mayús).
+    pub(crate) fn action_for(&self, key: &KeyEvent) -> Option<&KeyAction> {
+        self.map.get(&(key.code, key.modifiers)).or_else(|| match key.code {
+            KeyCode::Char(_) => self.map.get(&(key.code, key.modifiers - KeyModifiers::SHIFT)),
+            _ => None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_bindings_and_shift_normalisation() {
+        let bindings = KeyBindings::default();
+        let key = |code, modifiers| KeyEvent::new(code, modifiers);
+        assert!(matches!(bindings.action_for(&key(KeyCode::Char('c'), KeyModifiers::CONTROL)), Some(KeyAction::Quit)));
+        assert!(bindings.action_for(&key(KeyCode::Char('c'), KeyModifiers::NONE)).is_none());
+        assert!(matches!(bindings.action_for(&key(KeyCode::Char('+'), KeyModifiers::SHIFT)), Some(KeyAction::SpeedUp)));
+        let custom = bindings.unbind(KeyCode::Esc, KeyModifiers::NONE).on_key(KeyCode::Char('x'), |_| {});
+        assert!(custom.action_for(&key(KeyCode::Esc, KeyModifiers::NONE)).is_none());
+        assert!(matches!(custom.action_for(&key(KeyCode::Char('x'), KeyModifiers::NONE)), Some(KeyAction::Custom(_))));
+    }
+}
@@ -0,0 +1,123 @@
+// --- src/layout.rs ---
+//! Opciones de disposición del banner: alineación horizontal y bordes.
+
+use crate::banner::BannerContent;
+use crate::utils::get_line_width_accurate;
+
+/// Alineación horizontal del banner dentro de la terminal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Alignment {
+    Left,
+    #[default]
+    Center,
+    Right,
+}
+
+/// Estilos de borde disponibles para enmarcar el banner.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BorderStyle {
+    /// `┌─┐` Línea simple.
+    Single,
+    /// `╔═╗` Línea doble.
+    Double,
+    /// `╭─╮` Esquinas redondeadas.
+    Rounded,
+    /// `┏━┓` Línea gruesa.
+    Heavy,
+    /// `+-+` Solo caracteres ASCII, para terminales sin Unicode.
+    Ascii,
+}
+
+impl BorderStyle {
+    /// Caracteres del borde: (esq. sup. izq., esq. sup. der., esq. inf. izq., esq. inf. der., horizontal, vertical).
+    fn chars(self) -> (char, char, char, char, char, char) {
+        match self {
+            BorderStyle::Single => ('┌', '┐', '└', '┘', '─', '│'),
+            BorderStyle::Double => ('╔', '╗', '╚', '╝', '═', '║'),
+            BorderStyle::Rounded => ('╭', '╮', '╰', '╯', '─', '│'),
+            BorderStyle::Heavy => ('┏', '┓', '┗', '┛', '━', '┃'),
+            BorderStyle::Ascii => ('+', '+', '+', '+', '-', '|'),
+        }
+    }
+}
+
+/// Cómo se pinta el color de fondo (`Style::bg`) del banner.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BackgroundMode {
+    /// El fondo cubre cada línea tal cual, con su longitud propia.
+    #[default]
+    Glyphs,
+    /// Rellena todas las líneas hasta el ancho del banner, más los márgenes
+    /// indicados, de modo que el fondo forma un rectángulo limpio.
+    Panel { margin_x: u16, margin_y: u16 },
+    /// Solo los caracteres visibles llevan fondo; los espacios quedan sin él.
+    Transparent,
+}
+
+impl BackgroundMode {
+    /// Panel sin márgenes.
+    pub fn panel() -> Self {
+        BackgroundMode::Panel { margin_x: 0, margin_y: 0 }
+    }
+}
+
+/// Ancho máximo (en columnas) de las líneas del contenido.
+pub(crate) fn content_width(content: &[String]) -> u16 {
+    content.iter().map(|line| get_line_width_accurate(line)).max().unwrap_or(0)
+}
+
+/// Envuelve el contenido con el borde indicado, dejando un espacio de margen
+/// a cada lado. Todas las líneas resultantes tienen el mismo ancho.
+pub(crate) fn apply_border(content: &[String], border: BorderStyle) -> BannerContent {
+    let (tl, tr, bl, br, h, v) = border.chars();
+    let inner = content_width(content) as usize + 2;
+
+    let mut framed = Vec::with_capacity(content.len() + 2);
+    framed.push(format!("{}{}{}", tl, h.to_string().repeat(inner), tr));
+    for line in content {
+        let fill = inner - 1 - get_line_width_accurate(line) as usize;
+        framed.push(format!("{} {}{}{}", v, line, " ".repeat(fill), v));
+    }
+    framed.push(format!("{}{}{}", bl, h.to_string().repeat(inner), br));
+    framed
+}
+
+/// Rellena cada línea hasta el ancho común y añade los márgenes del panel.
+pub(crate) fn apply_panel(content: &[String], margin_x: u16, margin_y: u16) -> BannerContent {
+    let width = content_width(content) as usize;
+    let side = " ".repeat(margin_x as usize);
+    let blank = " ".repeat(width + 2 * margin_x as usize);
+
+    let mut panel = Vec::with_capacity(content.len() + 2 * margin_y as usize);
+    panel.extend(std::iter::repeat_n(blank.clone(), margin_y as usize));
+    for line in content {
+        let fill = width - get_line_width_accurate(line) as usize;
+        panel.push(format!("{}{}{}{}", side, line, " ".repeat(fill), side));
+    }
+    panel.extend(std::iter::repeat_n(blank, margin_y as usize));
+    panel
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_border_lines_have_equal_width() {
+        let content = vec!["ab".to_string(), "abcd".to_string()];
+        let framed = apply_border(&content, BorderStyle::Single);
+        assert_eq!(framed.len(), 4);
+        assert_eq!(framed[0], "┌──────┐");
+        assert_eq!(framed[1], "│ ab   │");
+        assert!(framed.iter().all(|l| get_line_width_accurate(l) == 8));
+    }
+
+    #[test]
+    fn test_panel_forms_rectangle() {
+        let content = vec!["a".to_string(), "abc".to_string()];
+        let panel = apply_panel(&content, 2, 1);
+        assert_eq!(panel.len(), 4);
+        assert_eq!(panel[1], "  a    ");
+        assert!(panel.iter().all(|l| get_line_width_accurate(l) == 7));
+    }
+}
@@ -3,11 +3,27 @@
 
 // --- Módulos ---
 mod banner;
+mod capabilities;
+mod color;
+mod effects;
 pub mod style; // <--- ¡AÑADIR 'pub' AQUÍ!
 mod animation;
+#[cfg(feature = "asciicast")]
+mod asciicast;
 mod renderer;
 mod error;
+mod export;
 mod fonts;
+mod generators;
+mod grid;
+mod handle;
+mod hyperlink;
+mod keys;
+mod layout;
+mod loaders;
+mod motion;
+mod overflow;
+mod theme;
 mod utils;
 
 // --- Presets (Condicional) ---
@@ -22,11 +38,27 @@ pub mod presets;
 
 // Opción 1: Mantener re-exportaciones clave + módulo público (flexible)
 pub use banner::{Banner, FigletOptions};
-pub use style::{Color, Style, Attribute, Rgb, DEFAULT_TEXT_COLOR, RUST_ORANGE, rgb}; // Re-exportar tipos/helpers comunes de style
+pub use style::{Color, Style, Attribute, Rgb, Gradient, GradientDirection, ColorSpec, ColorMode, DEFAULT_TEXT_COLOR, RUST_ORANGE, rgb, parse_color}; // Re-exportar tipos/helpers comunes de style
 // Los colores específicos (Red, Blue...) se accederán vía ::style::Red o use ::style::Red
 pub use animation::{Animation, Frame};
+pub use loaders::{TextFrames, DEFAULT_FRAME_SEPARATOR};
+pub use effects::{Easing, Edge, Reveal, RevealEffect};
+pub use generators::{Generator, GeneratorKind};
+pub use motion::{EdgeBehavior, Keyframe, Motion, MotionPath};
+pub use capabilities::{ColorDepth, ImageProtocol, TerminalCapabilities};
 pub use error::BannerError;
+pub use export::html::HtmlOptions;
+#[cfg(feature = "raster")]
+pub use export::raster::RasterOptions;
 pub use fonts::{FontSource, BuiltinFont};
+pub use layout::{Alignment, BackgroundMode, BorderStyle};
+pub use overflow::Overflow;
+pub use hyperlink::HyperlinkRegion;
+pub use renderer::{FallbackFrame, ScreenMode};
+pub use handle::AnimationHandle;
+pub use keys::{KeyAction, KeyBindings, KeyCallback};
+pub use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+pub use theme::{Theme, ThemeRegistry};
 
 // Opción 2: Solo hacer el módulo público y requerir `::style::` para todo lo de style
 // pub use banner::{Banner, FigletOptions};
@@ -0,0 +1,153 @@
+// --- src/loaders.rs ---
+//! Carga de animaciones desde archivos de texto plano, pensados para que los
+//! artistas dibujen los frames sin tocar código Rust.
+//!
+//! Formato: los frames se separan con una línea que contiene solo el
+//! separador (por defecto `---`). El separador puede llevar detrás la duración
+//! del frame que sigue (`--- 250ms`, `--- 1.5s` o `--- 250`, en milisegundos).
+//!
+//! ```text
+//! --- 500ms
+//!  (o_o)
+//! ---
+//!  (-_-)
+//! ```
+
+use crate::animation::{Animation, Frame};
+use crate::error::BannerError;
+use std::path::Path;
+use std::time::Duration;
+
+/// Separador de frames por defecto.
+pub const DEFAULT_FRAME_SEPARATOR: &str = "---";
+
+/// Lector configurable del formato de frames en texto plano.
+///
+/// ```no_run
+/// use vornix_banner::{Banner, TextFrames};
+///
+/// let animation = TextFrames::new().separator("%%").delay_ms(120).load("ferris.txt")?;
+/// Banner::from_animation(animation).display()?;
+/// # Ok::<(), vornix_banner::BannerError>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct TextFrames {
+    separator: String,
+    frame_delay: Duration,
+}
+
+impl Default for TextFrames {
+    fn default() -> Self {
+        Self { separator: DEFAULT_FRAME_SEPARATOR.to_string(), frame_delay: Duration::from_millis(100) }
+    }
+}
+
+impl TextFrames {
+    /// Lector con separador `---` y 100 ms por frame.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cambia la línea separadora (útil si los dibujos contienen `---`).
+    pub fn separator(mut self, separator: &str) -> Self { self.separator = separator.to_string(); self }
+    /// Duración de los frames sin directiva propia.
+    pub fn delay(mut self, delay: Duration) -> Self { self.frame_delay = delay; self }
+    pub fn delay_ms(self, ms: u64) -> Self { self.delay(Duration::from_millis(ms)) }
+
+    /// Lee y convierte un archivo.
+    pub fn load(&self, path: impl AsRef<Path>) -> Result<Animation, BannerError> {
+        self.parse(&std::fs::read_to_string(path)?)
+    }
+
+    /// Convierte el texto en una animación de una sola pasada.
+    pub fn parse(&self, text: &str) -> Result<Animation, BannerError> {
+        let mut animation = Animation::new(Vec::new(), self.frame_delay.as_millis() as u64).delay(self.frame_delay);
+        let mut current: Frame = Vec::new();
+        let mut current_delay = None;
+        // Un separador al principio del archivo solo aporta la directiva del primer frame.
+        let mut started = false;
+
+        for (number, line) in text.lines().enumerate() {
+            let line = line.trim_end_matches('\r');
+            let Some(directive) = self.separator_directive(line) else {
+                current.push(line.to_string());
+                started = true;
+                continue;
+            };
+            if started {
+                animation.push_frame(std::mem::take(&mut current), current_delay);
+            }
+            started = true;
+            current_delay = match directive {
+                "" => None,
+                d => Some(parse_duration(d).ok_or_else(|| {
+                    BannerError::InvalidAnimation(format!("Línea {}: duración de frame inválida '{}'", number + 1, d))
+                })?),
+            };
+        }
+        // Un separador al final del archivo no abre un frame vacío.
+        if !current.is_empty() {
+            animation.push_frame(current, current_delay);
+        }
+        if animation.frames.is_empty() {
+            return Err(BannerError::InvalidAnimation("El archivo no contiene frames".to_string()));
+        }
+        Ok(animation)
+    }
+
+    /// Si `line` es un separador, devuelve su directiva (posiblemente vacía).
+    fn separator_directive<'l>(&self, line: &'l str) -> Option<&'l str> {
+        let rest = line.trim_end().strip_prefix(self.separator.as_str())?;
+        if rest.is_empty() || rest.starts_with(char::is_whitespace) { Some(rest.trim()) } else { None }
+    }
+}
+
+/// Interpreta `250ms`, `1.5s` o `250` (milisegundos).
+fn parse_duration(text: &str) -> Option<Duration> {
+    let (number, scale) = if let Some(ms) = text.strip_suffix("ms") {
+        (ms, 0.001)
+    } else if let Some(s) = text.strip_suffix('s') {
+        (s, 1.0)
+    } else {
+        (text, 0.001)
+    };
+    let value: f64 = number.trim().parse().ok()?;
+    Duration::try_from_secs_f64(value * scale).ok()
+}
+
+impl Animation {
+    /// Carga una animación desde un archivo de texto con el formato por defecto
+    /// (ver `TextFrames`).
+    pub fn from_text_file(path: impl AsRef<Path>) -> Result<Self, BannerError> {
+        TextFrames::new().load(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_frames_and_directives() {
+        let text = "--- 500ms\n a\n\n---\n b\n--- 1.5s\n c\n---\n";
+        let animation = TextFrames::new().delay_ms(80).parse(text).unwrap();
+        assert_eq!(animation.frames, vec![
+            vec![" a".to_string(), String::new()],
+            vec![" b".to_string()],
+            vec![" c".to_string()],
+        ]);
+        assert_eq!(animation.delay_for(0), Duration::from_millis(500));
+        assert_eq!(animation.delay_for(1), Duration::from_millis(80));
+        assert_eq!(animation.delay_for(2), Duration::from_millis(1500));
+    }
+
+    #[test]
+    fn test_custom_separator_and_errors() {
+        let animation = TextFrames::new().separator("%%").parse("---\n%%\n-----").unwrap();
+        assert_eq!(animation.frames.len(), 2);
+        assert!(matches!(TextFrames::new().parse("a\n--- pronto"), Err(BannerError::InvalidAnimation(_))));
+        assert!(TextFrames::new().parse("---\n").is_err());
+        // Una duración que no cabe en un `Duration` es un error, no un panic.
+        assert!(matches!(TextFrames::new().parse("--- 1e30s\na"), Err(BannerError::InvalidAnimation(_))));
+    }
+}
@@ -0,0 +1,250 @@
+// --- src/motion.rs ---
+//! Movimiento de sprites: desplaza una animación pequeña (el sprite, con su
+//! propio ciclo de frames) por un escenario siguiendo una trayectoria.
+
+use crate::animation::{Animation, Frame};
+use crate::effects::Easing;
+
+/// Qué ocurre cuando el sprite alcanza un borde del escenario.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EdgeBehavior {
+    /// El sprite se detiene pegado al borde.
+    #[default]
+    Stop,
+    /// Lo que sale por un borde entra por el opuesto.
+    Wrap,
+}
+
+/// Posición (columna, fila) del sprite en un paso concreto de un recorrido por keyframes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Keyframe {
+    /// Paso (frame de salida) en el que el sprite está en `position`.
+    pub step: usize,
+    /// Esquina superior izquierda del sprite. Puede quedar fuera del escenario.
+    pub position: (i32, i32),
+    /// Curva usada para llegar a este keyframe desde el anterior.
+    pub easing: Easing,
+}
+
+impl Keyframe {
+    /// Keyframe con interpolación lineal.
+    pub fn new(step: usize, x: i32, y: i32) -> Self {
+        Self { step, position: (x, y), easing: Easing::Linear }
+    }
+
+    pub fn easing(mut self, easing: Easing) -> Self { self.easing = easing; self }
+}
+
+/// Trayectoria que sigue el sprite.
+#[derive(Debug, Clone, PartialEq)]
+pub enum MotionPath {
+    /// Cruza el escenario en horizontal por la fila `row`, `speed` columnas
+    /// por paso. Con velocidad negativa va de derecha a izquierda.
+    Walk { row: i32, speed: i32 },
+    /// Sale de `start` con `velocity` (columnas, filas) por paso y rebota en
+    /// los bordes del escenario.
+    Bounce { start: (i32, i32), velocity: (i32, i32) },
+    /// Pasa por cada keyframe (ordenados por `step`) interpolando entre ellos.
+    Keyframes(Vec<Keyframe>),
+}
+
+/// Combina el ciclo de frames de un sprite con su desplazamiento.
+///
+/// El resultado es una `Animation` cuyos frames miden lo mismo que el
+/// escenario, lista para `Banner::from_animation`. Pensado para sprites de
+/// texto plano (sin secuencias de escape); los espacios del sprite son
+/// transparentes.
+///
+/// ```no_run
+/// use vornix_banner::{Animation, Banner, EdgeBehavior, Motion, MotionPath};
+///
+/// let sprite = Animation::new(vec![vec!["o/".into()], vec!["o|".into()]], 120);
+/// let walk = Motion::new(sprite, MotionPath::Walk { row: 0, speed: 1 })
+///     .area(40, 1)
+///     .edges(EdgeBehavior::Wrap)
+///     .animation()
+///     .repeat(None);
+/// Banner::from_animation(walk).display().unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct Motion {
+    sprite: Animation,
+    path: MotionPath,
+    area: (u16, u16),
+    edges: EdgeBehavior,
+    steps: Option<usize>,
+}
+
+impl Motion {
+    /// Escenario por defecto: 80 columnas y el alto del sprite.
+    pub fn new(sprite: Animation, path: MotionPath) -> Self {
+        let height = sprite.frames.iter().map(Vec::len).max().unwrap_or(0) as u16;
+        Self { sprite, path, area: (80, height), edges: EdgeBehavior::default(), steps: None }
+    }
+
+    /// Tamaño del escenario en columnas y filas.
+    pub fn area(mut self, width: u16, height: u16) -> Self { self.area = (width, height); self }
+    pub fn edges(mut self, edges: EdgeBehavior) -> Self { self.edges = edges; self }
+
+    /// Número de frames a generar. Por defecto: lo que tarda `Walk` en cruzar
+    /// (o en dar la vuelta con `Wrap`), un ciclo completo de `Bounce` o hasta
+    /// el último keyframe.
+    pub fn steps(mut self, steps: usize) -> Self { self.steps = Some(steps); self }
+
+    /// Ancho y alto del sprite (el recuadro que contiene todos sus frames).
+    fn sprite_size(&self) -> (i32, i32) {
+        let width = self.sprite.frames.iter().flatten().map(|l| l.chars().count()).max().unwrap_or(0);
+        let height = self.sprite.frames.iter().map(Vec::len).max().unwrap_or(0);
+        (width as i32, height as i32)
+    }
+
+    /// Margen de movimiento en cada eje sin salirse del escenario.
+    fn room(&self) -> (i32, i32) {
+        let (w, h) = self.sprite_size();
+        ((self.area.0 as i32 - w).max(0), (self.area.1 as i32 - h).max(0))
+    }
+
+    fn default_steps(&self) -> usize {
+        let (room_x, room_y) = self.room();
+        let span = |distance: i32, speed: i32| if speed == 0 { 1 } else { (distance / speed.abs()).max(1) as usize };
+        match &self.path {
+            MotionPath::Walk { speed, .. } => match self.edges {
+                EdgeBehavior::Stop => span(room_x, *speed) + 1,
+                EdgeBehavior::Wrap => span(self.area.0 as i32, *speed),
+            },
+            MotionPath::Bounce { velocity: (vx, vy), .. } => span(2 * room_x, *vx).max(span(2 * room_y, *vy)),
+            MotionPath::Keyframes(keys) => keys.iter().map(|k| k.step + 1).max().unwrap_or(1),
+        }
+    }
+
+    /// Posición del sprite en el paso `step`, antes de aplicar `edges`.
+    fn position(&self, step: usize) -> (i32, i32) {
+        let (room_x, room_y) = self.room();
+        let step = step as i32;
+        match &self.path {
+            MotionPath::Walk { row, speed } => {
+                let start = if *speed < 0 { room_x } else { 0 };
+                (start + speed * step, *row)
+            }
+            MotionPath::Bounce { start, velocity } => {
+                (reflect(start.0 + velocity.0 * step, room_x), reflect(start.1 + velocity.1 * step, room_y))
+            }
+            MotionPath::Keyframes(keys) => interpolate(keys, step as usize),
+        }
+    }
+
+    /// Genera la animación: en cada paso, el siguiente frame del sprite en su
+    /// posición. Cada paso dura lo mismo que el frame del sprite que muestra.
+    pub fn animation(&self) -> Animation {
+        let cycle = self.sprite.frames.len();
+        let mut animation = Animation::new(Vec::new(), self.sprite.frame_delay.as_millis() as u64)
+            .delay(self.sprite.frame_delay)
+            .with_key_bindings(self.sprite.key_bindings.clone());
+        if cycle == 0 {
+            return animation;
+        }
+        for step in 0..self.steps.unwrap_or_else(|| self.default_steps()) {
+            let index = step % cycle;
+            let frame = self.place(&self.sprite.frames[index], self.position(step));
+            animation.push_frame(frame, Some(self.sprite.delay_for(index)));
+        }
+        animation
+    }
+
+    /// Dibuja `sprite` sobre un escenario vacío con su esquina en `(x, y)`.
+    fn place(&self, sprite: &Frame, (x, y): (i32, i32)) -> Frame {
+        let (width, height) = (self.area.0 as i32, self.area.1 as i32);
+        if width == 0 || height == 0 {
+            return vec![String::new(); height as usize];
+        }
+        let mut stage = vec![vec![' '; width as usize]; height as usize];
+        let (x, y) = match self.edges {
+            EdgeBehavior::Stop => {
+                let (room_x, room_y) = self.room();
+                (x.clamp(0, room_x), y.clamp(0, room_y))
+            }
+            EdgeBehavior::Wrap => (x, y),
+        };
+        for (dy, line) in sprite.iter().enumerate() {
+            for (dx, ch) in line.chars().enumerate() {
+                if ch == ' ' { continue; }
+                let (cx, cy) = (x + dx as i32, y + dy as i32);
+                let (cx, cy) = match self.edges {
+                    EdgeBehavior::Wrap => (cx.rem_euclid(width), cy.rem_euclid(height)),
+                    EdgeBehavior::Stop => (cx, cy),
+                };
+                if (0..width).contains(&cx) && (0..height).contains(&cy) {
+                    stage[cy as usize][cx as usize] = ch;
+                }
+            }
+        }
+        stage.into_iter().map(|row| row.into_iter().collect()).collect()
+    }
+}
+
+/// Posición de un punto que rebota entre `0` y `room`.
+fn reflect(position: i32, room: i32) -> i32 {
+    if room == 0 {
+        return 0;
+    }
+    let period = 2 * room;
+    let p = position.rem_euclid(period);
+    if p > room { period - p } else { p }
+}
+
+/// Posición en `step` interpolando entre los keyframes que lo rodean.
+fn interpolate(keys: &[Keyframe], step: usize) -> (i32, i32) {
+    let Some(first) = keys.first() else { return (0, 0) };
+    if step <= first.step {
+        return first.position;
+    }
+    for pair in keys.windows(2) {
+        let (from, to) = (pair[0], pair[1]);
+        if step <= to.step {
+            let span = to.step.saturating_sub(from.step).max(1) as f32;
+            let t = to.easing.apply((step - from.step) as f32 / span);
+            let lerp = |a: i32, b: i32| a + ((b - a) as f32 * t).round() as i32;
+            return (lerp(from.position.0, to.position.0), lerp(from.position.1, to.position.1));
+        }
+    }
+    keys[keys.len() - 1].position
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sprite() -> Animation {
+        Animation::new(vec![vec!["ab".into()], vec!["AB".into()]], 100)
+    }
+
+    #[test]
+    fn test_walk_cycles_sprite_frames_and_stops_at_edge() {
+        let walk = Motion::new(sprite(), MotionPath::Walk { row: 0, speed: 2 }).area(6, 1).animation();
+        let frames: Vec<_> = walk.frames.iter().map(|f| f[0].as_str()).collect();
+        assert_eq!(frames, ["ab    ", "  AB  ", "    ab"]);
+        let longer = Motion::new(sprite(), MotionPath::Walk { row: 0, speed: 2 }).area(6, 1).steps(4).animation();
+        assert_eq!(longer.frames[3][0], "    AB");
+    }
+
+    #[test]
+    fn test_wrap_reenters_from_the_opposite_edge() {
+        let walk = Motion::new(sprite(), MotionPath::Walk { row: 0, speed: 1 })
+            .area(4, 1)
+            .edges(EdgeBehavior::Wrap)
+            .steps(4)
+            .animation();
+        assert_eq!(walk.frames[3][0], "B  A");
+    }
+
+    #[test]
+    fn test_bounce_and_keyframes() {
+        let bounce = Motion::new(sprite(), MotionPath::Bounce { start: (0, 0), velocity: (1, 1) }).area(3, 2);
+        let positions: Vec<_> = (0..4).map(|s| bounce.position(s)).collect();
+        assert_eq!(positions, [(0, 0), (1, 1), (0, 0), (1, 1)]);
+
+        let keys = vec![Keyframe::new(0, 0, 0), Keyframe::new(4, 8, 2).easing(Easing::EaseIn)];
+        assert_eq!(interpolate(&keys, 2), (2, 1));
+        assert_eq!(interpolate(&keys, 9), (8, 2));
+    }
+}
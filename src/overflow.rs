@@ -0,0 +1,186 @@
+// --- src/overflow.rs ---
+//! Qué hacer cuando un banner FIGlet es más ancho que la terminal: recortarlo,
+//! repartir el texto en varias filas FIGlet, estrecharlo o mostrarlo como texto plano.
+
+use crate::banner::BannerContent;
+use crate::error::BannerError;
+use crate::layout::content_width;
+use unicode_width::UnicodeWidthChar;
+
+/// Política para banners más anchos que el espacio disponible.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Overflow {
+    /// No hace nada: la terminal parte las líneas que no caben.
+    #[default]
+    Ignore,
+    /// Recorta cada línea al ancho disponible. Con `ellipsis`, la última
+    /// columna de las líneas recortadas muestra `…` (o `>` sin Unicode).
+    Clip { ellipsis: bool },
+    /// Reparte las palabras del texto en varias filas FIGlet que quepan.
+    Wrap,
+    /// Estrecha el banner eliminando columnas a intervalos regulares.
+    Scale,
+    /// Muestra el texto original sin FIGlet.
+    PlainText,
+}
+
+/// Ajusta `content` (el FIGlet de `text`) a `width` columnas según `overflow`.
+/// `render` genera el FIGlet de un fragmento de texto (para `Wrap`).
+///
+/// Si la salida no es una terminal (`terminal` falso) el contenido se deja
+/// intacto: en un fichero o un log no hay ancho que respetar y recortar
+/// perdería texto.
+pub(crate) fn fit(
+    content: &BannerContent,
+    text: &str,
+    width: u16,
+    overflow: Overflow,
+    terminal: bool,
+    unicode: bool,
+    render: impl Fn(&str) -> Result<BannerContent, BannerError>,
+) -> Result<BannerContent, BannerError> {
+    let width = width as usize;
+    if !terminal || overflow == Overflow::Ignore || content_width(content) as usize <= width {
+        return Ok(content.clone());
+    }
+    let marker = if unicode { '…' } else { '>' };
+    Ok(match overflow {
+        Overflow::Ignore => content.clone(),
+        Overflow::Clip { ellipsis } => clip(content, width, ellipsis.then_some(marker)),
+        Overflow::Scale => scale(content, width),
+        Overflow::PlainText => clip(&vec![text.to_string()], width, Some(marker)),
+        Overflow::Wrap => {
+            let mut rows = Vec::new();
+            for line in wrap_words(text, width, &render)? {
+                rows.extend(clip(&render(&line)?, width, Some(marker)));
+            }
+            rows
+        }
+    })
+}
+
+/// Agrupa las palabras de `text` en líneas cuyo FIGlet quepa en `width`. Una
+/// palabra que no cabe sola se parte por caracteres.
+fn wrap_words(
+    text: &str,
+    width: usize,
+    render: &impl Fn(&str) -> Result<BannerContent, BannerError>,
+) -> Result<Vec<String>, BannerError> {
+    let fits = |candidate: &str| render(candidate).map(|c| content_width(&c) as usize <= width);
+    let mut lines: Vec<String> = Vec::new();
+    let mut current = String::new();
+    for word in text.split_whitespace() {
+        let candidate = if current.is_empty() { word.to_string() } else { format!("{} {}", current, word) };
+        if fits(&candidate)? {
+            current = candidate;
+            continue;
+        }
+        if !current.is_empty() {
+            lines.push(std::mem::take(&mut current));
+        }
+        for ch in word.chars() {
+            let candidate = format!("{}{}", current, ch);
+            if current.is_empty() || fits(&candidate)? {
+                current = candidate;
+            } else {
+                lines.push(std::mem::replace(&mut current, ch.to_string()));
+            }
+        }
+    }
+    if !current.is_empty() {
+        lines.push(current);
+    }
+    Ok(lines)
+}
+
+/// Recorta cada línea a `width` columnas. `marker` sustituye a la última
+/// columna de las líneas que perdían algo visible.
+fn clip(content: &BannerContent, width: usize, marker: Option<char>) -> BannerContent {
+    content
+        .iter()
+        .map(|line| {
+            let mut kept = String::new();
+            let mut used = 0;
+            let mut chars = line.chars();
+            for ch in chars.by_ref() {
+                let w = ch.width().unwrap_or(0);
+                if used + w > width {
+                    // Se devuelve el carácter que no cabe para comprobar si se pierde algo.
+                    let rest: String = std::iter::once(ch).chain(chars).collect();
+                    if let Some(marker) = marker.filter(|_| !rest.trim().is_empty() && width > 0) {
+                        kept.pop();
+                        kept.push(marker);
+                    }
+                    return kept;
+                }
+                kept.push(ch);
+                used += w;
+            }
+            kept
+        })
+        .collect()
+}
+
+/// Estrecha el contenido a `width` columnas conservando columnas repartidas
+/// uniformemente (las mismas en todas las líneas, para no deformar el FIGlet).
+fn scale(content: &BannerContent, width: usize) -> BannerContent {
+    let source = content_width(content) as usize;
+    content
+        .iter()
+        .map(|line| {
+            let chars: Vec<char> = line.chars().collect();
+            (0..width)
+                .map(|x| chars.get(x * source / width).copied().unwrap_or(' '))
+                .collect::<String>()
+                .trim_end()
+                .to_string()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// FIGlet de prueba: cada carácter ocupa tres columnas y dos filas.
+    fn render(text: &str) -> Result<BannerContent, BannerError> {
+        let row: String = text.chars().map(|c| format!("{}{} ", c, c)).collect();
+        Ok(vec![row.clone(), row])
+    }
+
+    fn content(text: &str) -> BannerContent {
+        render(text).unwrap()
+    }
+
+    #[test]
+    fn test_content_that_fits_is_untouched() {
+        let fitted = fit(&content("ab"), "ab", 6, Overflow::Scale, true, true, render).unwrap();
+        assert_eq!(fitted, content("ab"));
+    }
+
+    #[test]
+    fn test_output_that_is_not_a_terminal_is_never_cut() {
+        let wide = content("abcd");
+        for overflow in [Overflow::Clip { ellipsis: true }, Overflow::Wrap, Overflow::Scale, Overflow::PlainText] {
+            assert_eq!(fit(&wide, "abcd", 6, overflow, false, true, render).unwrap(), wide);
+        }
+    }
+
+    #[test]
+    fn test_clip_scale_and_plain_text() {
+        let wide = content("abcd"); // 12 columnas
+        assert_eq!(fit(&wide, "abcd", 7, Overflow::Clip { ellipsis: false }, true, true, render).unwrap()[0], "aa bb c");
+        assert_eq!(fit(&wide, "abcd", 7, Overflow::Clip { ellipsis: true }, true, false, render).unwrap()[0], "aa bb >");
+        assert_eq!(fit(&wide, "abcd", 6, Overflow::Scale, true, true, render).unwrap()[0], "a bc d");
+        assert_eq!(fit(&wide, "abcd", 6, Overflow::PlainText, true, true, render).unwrap(), vec!["abcd"]);
+    }
+
+    #[test]
+    fn test_wrap_splits_words_into_figlet_rows() {
+        let fitted = fit(&content("ab cd"), "ab cd", 8, Overflow::Wrap, true, true, render).unwrap();
+        assert_eq!(fitted, [content("ab"), content("cd")].concat());
+        // Una palabra que no cabe sola se parte por caracteres.
+        let fitted = fit(&content("abcde"), "abcde", 6, Overflow::Wrap, true, true, render).unwrap();
+        assert_eq!(fitted, [content("ab"), content("cd"), content("e")].concat());
+    }
+}
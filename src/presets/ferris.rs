@@ -4,6 +4,7 @@
 
 use crate::{
     animation::{Animation, Frame},
+    motion::{EdgeBehavior, Motion, MotionPath},
     banner::Banner, // BannerContent no es estrictamente necesario aquí si usamos Frame
     style::{Style, FERRIS_BODY},
 };
@@ -34,20 +35,23 @@ fn make_frame(lines: &[&str]) -> Frame {
     lines.iter().map(|s| s.to_string()).collect()
 }
 
+/// Los cuatro frames del paso de Ferris.
+fn walk_cycle() -> Vec<Frame> {
+    vec![
+        make_frame(FERRIS_FRAME_1),
+        make_frame(FERRIS_FRAME_2),
+        make_frame(FERRIS_FRAME_3),
+        make_frame(FERRIS_FRAME_4),
+    ]
+}
+
 /// Crea un `Banner` configurado con la animación de Ferris caminando.
 ///
 /// La animación se repite indefinidamente y se muestra centrada con un
 /// estilo naranja por defecto.
 #[cfg(feature = "presets")]
 pub fn walking_ferris() -> Banner {
-    let frames = vec![
-        make_frame(FERRIS_FRAME_1),
-        make_frame(FERRIS_FRAME_2),
-        make_frame(FERRIS_FRAME_3),
-        make_frame(FERRIS_FRAME_4),
-    ];
-
-    let animation = Animation::new(frames, 180) // Delay en ms
+    let animation = Animation::new(walk_cycle(), 180) // Delay en ms
         .repeat(None); // Repetir indefinidamente
 
     // Crear el Banner usando from_animation
@@ -55,4 +59,20 @@ pub fn walking_ferris() -> Banner {
         .with_style(Style::new().fg(FERRIS_BODY))
         .centered(true)
         .padding_top(1)
-}
\ No newline at end of file
+}
+
+/// Ferris cruza de izquierda a derecha un escenario de `width` columnas,
+/// moviendo las patas mientras avanza, y vuelve a entrar por la izquierda.
+#[cfg(feature = "presets")]
+pub fn crossing_ferris(width: u16) -> Banner {
+    let sprite = Animation::new(walk_cycle(), 120);
+    let animation = Motion::new(sprite, MotionPath::Walk { row: 0, speed: 1 })
+        .area(width, FERRIS_FRAME_1.len() as u16)
+        .edges(EdgeBehavior::Wrap)
+        .animation()
+        .repeat(None);
+
+    Banner::from_animation(animation)
+        .with_style(Style::new().fg(FERRIS_BODY))
+        .padding_top(1)
+}
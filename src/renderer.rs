@@ -4,54 +4,219 @@
 
 use crate::animation::Animation;
 use crate::banner::BannerContent;
+use crate::capabilities::TerminalCapabilities;
 use crate::error::BannerError;
-use crate::style::Style;
+use crate::grid::Grid;
+use crate::handle::PlaybackControl;
+use crate::hyperlink::{self, HyperlinkRegion};
+use crate::keys::KeyAction;
+use crate::layout::{apply_border, apply_panel, content_width, Alignment, BackgroundMode, BorderStyle};
+use crate::style::{ColorMode, Style};
 use crate::utils::get_line_width_accurate; // Para cálculo preciso de ancho
 use crossterm::{
     cursor,
-    event::{poll, read, Event, KeyCode, KeyEvent, KeyModifiers},
+    event::{poll, read, Event, KeyEvent, KeyEventKind},
     execute, queue, style as crossterm_style,
     terminal::{self, ClearType},
 };
-use std::{io::{self, stdout, Stdout, Write}, panic::{self, PanicHookInfo}}; // Importar io para usarlo en get_terminal_width
+use std::{io::{self, stdout, Stdout, Write}, panic::{self, PanicHookInfo}, time::{Duration, Instant}}; // Importar io para usarlo en get_terminal_width
+
+use terminal_size::Width;
+
+/// Opciones de presentación compartidas por los renderizadores.
+pub(crate) struct RenderOptions<'a> {
+    pub style: &'a Style,
+    pub alignment: Alignment,
+    pub padding_top: u16,
+    pub border: Option<BorderStyle>,
+    /// Hipervínculo que envuelve todo el banner.
+    pub hyperlink: Option<&'a str>,
+    pub color_mode: ColorMode,
+    pub background_mode: BackgroundMode,
+    pub screen_mode: ScreenMode,
+    /// Ancho supuesto cuando no se puede consultar la terminal.
+    pub fallback_width: u16,
+    /// Frame que se imprime en lugar de la animación si la salida no es una TTY.
+    pub fallback_frame: FallbackFrame,
+    /// Si es `false` se omiten los hipervínculos OSC 8.
+    pub hyperlinks: bool,
+}
+
+impl<'a> RenderOptions<'a> {
+    /// Ajusta las opciones a lo que admite la terminal: borde ASCII sin
+    /// Unicode, modo en línea sin pantalla alternativa y sin hipervínculos
+    /// donde no se muestran.
+    pub(crate) fn for_terminal(&self, caps: &TerminalCapabilities) -> RenderOptions<'a> {
+        RenderOptions {
+            border: self.border.map(|border| if caps.unicode { border } else { BorderStyle::Ascii }),
+            screen_mode: if caps.alternate_screen { self.screen_mode } else { ScreenMode::Inline },
+            hyperlinks: self.hyperlinks && caps.hyperlinks,
+            ..*self
+        }
+    }
+
+    /// Aplica el borde (si lo hay) y el relleno del panel de fondo al contenido.
+    pub(crate) fn decorate(&self, content: &[String]) -> BannerContent {
+        let content = match self.border {
+            Some(border) => apply_border(content, border),
+            None => content.to_vec(),
+        };
+        match self.background_mode {
+            BackgroundMode::Panel { margin_x, margin_y } => apply_panel(&content, margin_x, margin_y),
+            _ => content,
+        }
+    }
+
+    /// Desplazamiento (filas, columnas) que introduce la decoración respecto al contenido original.
+    pub(crate) fn content_offset(&self) -> (usize, usize) {
+        let (mut rows, mut columns) = if self.border.is_some() { (1, 2) } else { (0, 0) };
+        if let BackgroundMode::Panel { margin_x, margin_y } = self.background_mode {
+            rows += margin_y as usize;
+            columns += margin_x as usize;
+        }
+        (rows, columns)
+    }
+
+    /// Estiliza un fragmento que empieza en `column`. En modo transparente los
+    /// espacios se pintan sin fondo.
+    fn paint(&self, text: &str, row: usize, column: usize, extent: (u16, u16)) -> String {
+        if self.background_mode != BackgroundMode::Transparent || self.style.background.is_none() {
+            return self.style.apply_line(text, row, column, extent);
+        }
+        let bare = self.style.without_background();
+        let mut out = String::new();
+        let mut run = String::new();
+        let mut run_start = column;
+        let mut col = column;
+        let mut run_is_space = false;
+        for ch in text.chars() {
+            let is_space = ch == ' ';
+            if !run.is_empty() && is_space != run_is_space {
+                let style = if run_is_space { &bare } else { self.style };
+                out.push_str(&style.apply_line(&run, row, run_start, extent));
+                run.clear();
+                run_start = col;
+            }
+            run_is_space = is_space;
+            run.push(ch);
+            col += unicode_width::UnicodeWidthChar::width(ch).unwrap_or(0);
+        }
+        if !run.is_empty() {
+            let style = if run_is_space { &bare } else { self.style };
+            out.push_str(&style.apply_line(&run, row, run_start, extent));
+        }
+        out
+    }
 
-use terminal_size::{Height, Width}; // Importar Width y Height
+    /// Estiliza una línea ya decorada, añadiendo los hipervínculos que correspondan.
+    /// Si `escapes` es falso la línea se devuelve tal cual.
+    fn render_line(
+        &self,
+        line: &str,
+        row: usize,
+        extent: (u16, u16),
+        regions: &[HyperlinkRegion],
+        escapes: bool,
+    ) -> String {
+        if !escapes {
+            return line.to_string();
+        }
+        let regions = if self.hyperlinks { regions } else { &[] };
+        let styled = if regions.is_empty() {
+            self.paint(line, row, 0, extent)
+        } else {
+            hyperlink::segments(line, row, regions, self.content_offset())
+                .into_iter()
+                .map(|(column, text, url)| {
+                    let styled = self.paint(&text, row, column, extent);
+                    match url {
+                        Some(url) => hyperlink::wrap(&styled, url),
+                        None => styled,
+                    }
+                })
+                .collect()
+        };
+        match self.hyperlink.filter(|_| self.hyperlinks) {
+            Some(url) => hyperlink::wrap(&styled, url),
+            None => styled,
+        }
+    }
+}
+
+/// Hook de panic guardado mientras la terminal está en modo raw.
+type PanicHook = Box<dyn Fn(&PanicHookInfo<'_>) + Send + Sync + 'static>;
+
+/// Dónde se dibujan las animaciones.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ScreenMode {
+    /// Pantalla alternativa: la animación desaparece al terminar.
+    #[default]
+    AlternateScreen,
+    /// En línea: reserva filas en la posición actual del cursor, anima en su
+    /// sitio y deja el último frame impreso en el historial de la terminal.
+    Inline,
+}
+
+/// Frame de una animación que se imprime, una sola vez, cuando la salida no
+/// es una terminal (tuberías, CI, logs de systemd o docker).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FallbackFrame {
+    First,
+    /// El último frame: con un efecto de aparición, el banner completo.
+    #[default]
+    Last,
+}
+
+impl FallbackFrame {
+    /// Índice del frame elegido entre `len` frames (`len` > 0).
+    fn index(self, len: usize) -> usize {
+        match self {
+            FallbackFrame::First => 0,
+            FallbackFrame::Last => len - 1,
+        }
+    }
+}
 
 // --- Terminal Guard ---
 /// Gestiona el estado de la terminal (modo raw, pantalla alternativa, cursor)
 /// y asegura su restauración incluso en caso de panic.
 struct TerminalGuard {
     // Usar PanicHookInfo si está disponible y no da problemas, si no, mantener PanicInfo por compatibilidad temporal
-    original_panic_hook: Option<Box<dyn Fn(&PanicHookInfo<'_>) + Send + Sync + 'static>>,
-    // Alternativa:
-    // original_panic_hook: Option<Box<dyn Fn(&PanicHookInfo<'_>) + Send + Sync + 'static>>,
+    original_panic_hook: Option<PanicHook>,
+    mode: ScreenMode,
 }
 
 impl TerminalGuard {
-    /// Entra en modo raw, cambia a pantalla alternativa y oculta el cursor.
-    /// Instala un hook de panic para asegurar la restauración.
-    fn new() -> Result<Self, BannerError> {
+    /// Entra en modo raw, cambia a pantalla alternativa (salvo en modo en línea)
+    /// y oculta el cursor. Instala un hook de panic para asegurar la restauración.
+    fn new(mode: ScreenMode) -> Result<Self, BannerError> {
         // 1. Guardar el hook original
         let original_hook = panic::take_hook();
 
         // 2. Establecer un hook simple que SOLO restaura la terminal
         panic::set_hook(Box::new(move |_panic_info: &PanicHookInfo<'_>| {
             // No llamamos al hook original aquí, solo restauramos
-            let _ = Self::restore_terminal_static();
+            let _ = Self::restore_terminal_static(mode);
         }));
 
         let mut stdout = stdout();
         terminal::enable_raw_mode()?;
-        execute!(stdout, cursor::Hide, terminal::EnterAlternateScreen)?;
+        execute!(stdout, cursor::Hide)?;
+        if mode == ScreenMode::AlternateScreen {
+            execute!(stdout, terminal::EnterAlternateScreen)?;
+        }
 
         // 3. Guardar el hook original en la struct
-        Ok(Self { original_panic_hook: Some(original_hook) })
+        Ok(Self { original_panic_hook: Some(original_hook), mode })
     }
 
     /// Restaura la terminal a su estado original (estático para el hook de panic).
-    fn restore_terminal_static() -> Result<(), BannerError> {
+    fn restore_terminal_static(mode: ScreenMode) -> Result<(), BannerError> {
         let mut stdout = stdout();
-        execute!(stdout, terminal::LeaveAlternateScreen, cursor::Show)?;
+        if mode == ScreenMode::AlternateScreen {
+            execute!(stdout, terminal::LeaveAlternateScreen)?;
+        }
+        execute!(stdout, cursor::Show)?;
         terminal::disable_raw_mode()?;
         Ok(())
     }
@@ -61,7 +226,7 @@ impl TerminalGuard {
         if let Some(hook) = self.original_panic_hook.take() {
             panic::set_hook(hook);
         }
-        Self::restore_terminal_static()
+        Self::restore_terminal_static(self.mode)
     }
 }
 
@@ -75,142 +240,570 @@ impl Drop for TerminalGuard {
 }
 
 
-/// Renderiza contenido estático (ej. FIGlet generado) en la terminal.
+/// Renderiza contenido estático (ej. FIGlet generado) en la terminal. Si la
+/// salida no es una TTY se imprimen líneas normales, centradas respecto a
+/// `fallback_width`.
 pub(crate) fn display_static_content(
     content: &BannerContent,
-    style: &Style,
-    centered: bool,
-    padding_top: u16,
+    options: &RenderOptions,
 ) -> Result<(), BannerError> {
-    let mut stdout = stdout();
-    let terminal_width = get_terminal_width().unwrap_or(80); // Mantenemos fallback
+    let caps = TerminalCapabilities::current();
+    let options = &options.for_terminal(&caps);
+    let terminal_width = get_terminal_width().unwrap_or(options.fallback_width);
+    write_static_content(&mut stdout(), content, options, terminal_width, caps.is_tty)
+}
 
-    apply_vertical_padding(&mut stdout, padding_top)?;
+/// Escribe el contenido decorado línea a línea. Fuera de una terminal los
+/// saltos de línea son `\n` en lugar de `\r\n`.
+fn write_static_content(
+    out: &mut impl Write,
+    content: &BannerContent,
+    options: &RenderOptions,
+    terminal_width: u16,
+    terminal: bool,
+) -> Result<(), BannerError> {
+    let newline = if terminal { "\r\n" } else { "\n" };
+    apply_vertical_padding(out, options.padding_top, newline)?;
 
-    for line in content {
+    let content = options.decorate(content);
+    let extent = (content_width(&content), content.len() as u16);
+    let escapes = options.color_mode.escapes_enabled();
+    for (i, line) in content.iter().enumerate() {
         let line_width = get_line_width_accurate(line);
-        let padding = calculate_padding(terminal_width, line_width, centered);
-        let styled_line = style.apply(line);
+        let padding = calculate_padding(terminal_width, line_width, options.alignment);
+        let styled_line = options.render_line(line, i, extent, &[], escapes);
 
         queue!(
-            stdout,
+            out,
             crossterm_style::Print(" ".repeat(padding as usize)),
             crossterm_style::Print(&styled_line),
-            crossterm_style::Print("\r\n")
+            crossterm_style::Print(newline)
         )?;
     }
-    stdout.flush()?;
+    out.flush()?;
     Ok(())
 }
 
+/// Sin TTY no hay modo raw ni cursor que mover: la animación se reduce a
+/// imprimir una vez el frame indicado por `fallback_frame`.
+fn display_without_terminal(animation: &Animation, options: &RenderOptions) -> Result<(), BannerError> {
+    let frame = &animation.frames[options.fallback_frame.index(animation.frames.len())];
+    display_static_content(frame, options)
+}
+
 /// Renderiza una animación de frames predefinidos.
 pub(crate) fn display_animated(
     animation: &Animation,
-    style: &Style,
-    centered: bool,
-    padding_top: u16,
+    options: &RenderOptions,
+    control: &PlaybackControl,
 ) -> Result<(), BannerError> {
-    if animation.frames.is_empty() { return Ok(()); }
+    let animation = &*animation.prepared()?;
+    let caps = TerminalCapabilities::current();
+    let options = &options.for_terminal(&caps);
+    if !caps.is_tty {
+        return display_without_terminal(animation, options);
+    }
 
-    let mut _guard = TerminalGuard::new()?;
-    let mut stdout = stdout();
-    let terminal_width = get_terminal_width().unwrap_or(80); // Mantenemos fallback
+    let mut _guard = TerminalGuard::new(options.screen_mode)?;
+    let mut playback = Playback::start(animation, options)?;
+    loop {
+        let wait = playback.draw()?;
+        match wait_frame(&mut playback, wait, control)? {
+            Step::Next => if !playback.advance() { break; },
+            Step::Redraw => {}
+            Step::Quit => break,
+        }
+    }
+    playback.finish()
+}
 
-    let mut current_repeat = 0;
-    let mut frame_index = 0;
+/// Variante asíncrona de `display_animated`: las esperas usan temporizadores de
+/// tokio y el teclado se lee con el `EventStream` de crossterm. Si el future se
+/// suelta a medias, el `TerminalGuard` restaura la terminal igualmente.
+#[cfg(feature = "async")]
+pub(crate) async fn display_animated_async(
+    animation: &Animation,
+    options: &RenderOptions<'_>,
+) -> Result<(), BannerError> {
+    let animation = &*animation.prepared()?;
+    let caps = TerminalCapabilities::current();
+    let options = &options.for_terminal(&caps);
+    if !caps.is_tty {
+        return display_without_terminal(animation, options);
+    }
 
+    let _guard = TerminalGuard::new(options.screen_mode)?;
+    let mut events = crossterm::event::EventStream::new();
+    let mut playback = Playback::start(animation, options)?;
     loop {
-        if poll(animation.frame_delay)? {
-            match read()? {
-                Event::Key(KeyEvent { code: KeyCode::Esc, .. }) |
-                Event::Key(KeyEvent { code: KeyCode::Char('q'), .. }) |
-                Event::Key(KeyEvent { code: KeyCode::Char('c'), modifiers: KeyModifiers::CONTROL, .. }) => {
-                    break;
-                }
-                _ => {}
-            }
+        let wait = playback.draw()?;
+        match wait_frame_async(&mut playback, wait, &mut events).await? {
+            Step::Next => if !playback.advance() { break; },
+            Step::Redraw => {}
+            Step::Quit => break,
         }
+    }
+    playback.finish()
+}
 
-        let frame_content = &animation.frames[frame_index];
-        queue!( stdout, terminal::Clear(ClearType::Purge), cursor::MoveTo(0, 0) )?;
-        apply_vertical_padding(&mut stdout, padding_top)?;
+/// Qué hacer al terminar la espera de un frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Step {
+    /// Pasar al siguiente frame.
+    Next,
+    /// Volver a dibujar el frame actual (tras saltar a otro frame con el teclado).
+    Redraw,
+    /// Terminar la animación.
+    Quit,
+}
+
+/// Límites de la velocidad ajustable con el teclado.
+const MIN_SPEED: f32 = 0.125;
+const MAX_SPEED: f32 = 8.0;
+
+/// Estado de una reproducción en curso: zona de dibujo, último frame emitido
+/// y posición dentro de la animación. Lo comparten los bucles síncrono y asíncrono.
+/// La salida es genérica para poder probarla sin una terminal real.
+struct Playback<'a, 'o, W: Write = Stdout> {
+    animation: &'a Animation,
+    options: &'a RenderOptions<'o>,
+    stdout: W,
+    escapes: bool,
+    synchronized: bool,
+    terminal_width: u16,
+    origin_row: u16,
+    area_height: u16,
+    previous: Grid,
+    frame_index: usize,
+    current_repeat: usize,
+    /// Pausa pedida con el teclado (independiente de la de `AnimationHandle`).
+    paused: bool,
+    /// Multiplicador de velocidad elegido con el teclado.
+    speed: f32,
+}
 
-        // --- CORRECCIÓN AQUÍ ---
-        // Obtener posición, manejar Result con '?', luego acceder al elemento .1 (row)
-        let start_row = match cursor::position() {
-             Ok((_, r)) => r, // Si Ok, obtener la fila (r)
-             Err(_) => padding_top, // Si falla, usar un fallback razonable (ej: padding_top)
-                                    // O podrías propagar el error: Err(e) => return Err(BannerError::Io(e.into()))?
-                                    // Depende de cuán crítico sea obtener la posición exacta.
+impl<'a, 'o> Playback<'a, 'o> {
+    /// Prepara la zona de dibujo. La terminal ya debe estar en modo raw.
+    fn start(animation: &'a Animation, options: &'a RenderOptions<'o>) -> Result<Self, BannerError> {
+        let mut stdout = stdout();
+        let (terminal_width, terminal_height) = terminal::size().unwrap_or((80, 24)); // Mantenemos fallback
+
+        // Zona de dibujo: toda la pantalla alternativa, o solo las filas reservadas en modo en línea.
+        let (origin_row, area_height) = match options.screen_mode {
+            ScreenMode::AlternateScreen => {
+                // La pantalla alternativa empieza vacía: el primer frame se compara con una rejilla en blanco.
+                queue!(stdout, terminal::Clear(ClearType::All))?;
+                (0, terminal_height)
+            }
+            ScreenMode::Inline => {
+                let frame_height = animation.frames.iter().map(|f| options.decorate(f).len()).max().unwrap_or(0);
+                let rows = (options.padding_top as usize + frame_height).min(terminal_height as usize) as u16;
+                reserve_inline_rows(&mut stdout, rows)?
+            }
         };
-        // Alternativa más corta si quieres propagar el error:
-        // let start_row = cursor::position()?.1;
-
-
-        for (i, line) in frame_content.iter().enumerate() {
-            let line_width = get_line_width_accurate(line);
-            let padding = calculate_padding(terminal_width, line_width, centered);
-            let styled_line = style.apply(line);
-
-            queue!(
-                stdout,
-                // Usar start_row calculado
-                cursor::MoveTo(padding, start_row.saturating_add(i as u16)), // Usar saturating_add por si acaso
-                crossterm_style::Print(&styled_line)
-            )?;
-        }
-        stdout.flush()?;
-
-        frame_index += 1;
-        if frame_index >= animation.frames.len() {
-            frame_index = 0;
-            match animation.repeat {
-                Some(count) => {
-                    current_repeat += 1;
-                    if current_repeat >= count { break; }
+
+        Ok(Self::new(animation, options, stdout, terminal_width, (origin_row, area_height)))
+    }
+}
+
+impl<'a, 'o, W: Write> Playback<'a, 'o, W> {
+    /// Reproducción sobre una zona ya preparada: `area` es (fila inicial, altura).
+    fn new(
+        animation: &'a Animation,
+        options: &'a RenderOptions<'o>,
+        stdout: W,
+        terminal_width: u16,
+        (origin_row, area_height): (u16, u16),
+    ) -> Self {
+        Self {
+            animation,
+            options,
+            stdout,
+            escapes: options.color_mode.escapes_enabled(),
+            synchronized: TerminalCapabilities::with_current(|caps| caps.synchronized_output),
+            terminal_width,
+            origin_row,
+            area_height,
+            previous: Grid::new(terminal_width, area_height),
+            frame_index: 0,
+            current_repeat: 0,
+            paused: false,
+            speed: 1.0,
+        }
+    }
+
+    /// Dibuja el frame actual y devuelve cuánto debe permanecer en pantalla.
+    fn draw(&mut self) -> Result<Duration, BannerError> {
+        let animation = self.animation;
+        let frame = compose_frame(
+            &animation.frames[self.frame_index],
+            self.options,
+            &animation.links,
+            self.escapes,
+            (self.terminal_width, self.area_height),
+        );
+
+        // Solo se emiten las celdas que cambian respecto al frame anterior.
+        let changes = frame.diff(&self.previous, self.origin_row);
+        if self.synchronized { queue!(self.stdout, terminal::BeginSynchronizedUpdate)?; }
+        queue!(self.stdout, crossterm_style::Print(changes))?;
+        if self.synchronized { queue!(self.stdout, terminal::EndSynchronizedUpdate)?; }
+        self.stdout.flush()?;
+        self.previous = frame;
+
+        // Cada frame permanece su propia duración; el último de la última
+        // repetición añade además `hold_last`.
+        let is_last_frame = self.frame_index + 1 == animation.frames.len();
+        let is_final_pass = animation.repeat.is_some_and(|count| self.current_repeat + 1 >= count);
+        let mut wait = animation.delay_for(self.frame_index);
+        if is_last_frame && is_final_pass {
+            wait = wait.saturating_add(animation.hold_last);
+        }
+        Ok(wait)
+    }
+
+    /// Avanza al siguiente frame. Devuelve `false` cuando la animación terminó.
+    fn advance(&mut self) -> bool {
+        self.frame_index += 1;
+        if self.frame_index >= self.animation.frames.len() {
+            self.frame_index = 0;
+            if let Some(count) = self.animation.repeat {
+                self.current_repeat += 1;
+                if self.current_repeat >= count { return false; }
+            }
+        }
+        true
+    }
+
+    /// Adapta la zona de dibujo al nuevo tamaño de la terminal. La zona se
+    /// limpia y se olvida el último frame, de modo que el siguiente `draw`
+    /// redibuja el frame actual por completo, recentrado y recortado.
+    fn resize(&mut self, width: u16, height: u16) -> Result<(), BannerError> {
+        self.terminal_width = width;
+        match self.options.screen_mode {
+            ScreenMode::AlternateScreen => {
+                self.area_height = height;
+                queue!(self.stdout, terminal::Clear(ClearType::All))?;
+            }
+            ScreenMode::Inline => {
+                // Las filas reservadas se mantienen mientras quepan en la pantalla.
+                self.area_height = self.area_height.min(height);
+                self.origin_row = self.origin_row.min(height - self.area_height);
+                queue!(self.stdout, cursor::MoveTo(0, self.origin_row), terminal::Clear(ClearType::FromCursorDown))?;
+            }
+        }
+        self.previous = Grid::new(width, self.area_height);
+        Ok(())
+    }
+
+    /// Atiende un evento de terminal durante la espera de un frame.
+    fn handle_event(&mut self, event: Event) -> Result<Option<Step>, BannerError> {
+        match event {
+            Event::Key(key) => Ok(self.handle_key(key)),
+            Event::Resize(width, height) => {
+                self.resize(width, height)?;
+                Ok(Some(Step::Redraw))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// Aplica la acción asignada a `key`, si la hay. Devuelve el paso a dar
+    /// cuando la tecla interrumpe la espera del frame actual.
+    fn handle_key(&mut self, key: KeyEvent) -> Option<Step> {
+        if key.kind != KeyEventKind::Press {
+            return None;
+        }
+        let frame_count = self.animation.frames.len();
+        match self.animation.key_bindings.action_for(&key)? {
+            KeyAction::Quit => return Some(Step::Quit),
+            KeyAction::TogglePause => self.paused = !self.paused,
+            KeyAction::StepForward => {
+                self.paused = true;
+                self.frame_index = (self.frame_index + 1) % frame_count;
+                return Some(Step::Redraw);
+            }
+            KeyAction::StepBackward => {
+                self.paused = true;
+                self.frame_index = (self.frame_index + frame_count - 1) % frame_count;
+                return Some(Step::Redraw);
+            }
+            KeyAction::Restart => {
+                self.frame_index = 0;
+                self.current_repeat = 0;
+                return Some(Step::Redraw);
+            }
+            KeyAction::SpeedUp => self.speed = (self.speed * 2.0).min(MAX_SPEED),
+            KeyAction::SlowDown => self.speed = (self.speed / 2.0).max(MIN_SPEED),
+            KeyAction::SkipToEnd => {
+                self.paused = false;
+                self.frame_index = frame_count - 1;
+                if let Some(count) = self.animation.repeat {
+                    self.current_repeat = count.saturating_sub(1);
                 }
-                None => {}
+                return Some(Step::Redraw);
             }
+            KeyAction::Custom(callback) => callback(key),
         }
+        None
+    }
+
+    /// Cierra la reproducción. En modo en línea deja el cursor bajo el último
+    /// frame, que queda en el historial.
+    fn finish(mut self) -> Result<(), BannerError> {
+        if self.options.screen_mode == ScreenMode::Inline {
+            queue!(self.stdout, cursor::MoveTo(0, self.origin_row.saturating_add(self.area_height)))?;
+            self.stdout.flush()?;
+        }
+        Ok(())
     }
-    Ok(())
 }
 
 
 // --- Funciones Helper ---
 
-/// Obtiene el ancho actual de la terminal.
+/// Reserva `rows` filas a partir del cursor (desplazando la pantalla si hace
+/// falta). Devuelve la fila inicial de la zona reservada y su altura.
+fn reserve_inline_rows(stdout: &mut Stdout, rows: u16) -> Result<(u16, u16), BannerError> {
+    queue!(stdout, crossterm_style::Print("\r\n".repeat(rows as usize)))?;
+    stdout.flush()?;
+    let (_, row) = cursor::position()?;
+    Ok((row.saturating_sub(rows), rows))
+}
+
+/// Compone un frame completo (decorado, estilizado y posicionado) sobre una
+/// rejilla del tamaño de la terminal.
+pub(crate) fn compose_frame(
+    frame: &[String],
+    options: &RenderOptions,
+    links: &[HyperlinkRegion],
+    escapes: bool,
+    (width, height): (u16, u16),
+) -> Grid {
+    let content = options.decorate(frame);
+    let extent = (content_width(&content), content.len() as u16);
+    let mut grid = Grid::new(width, height);
+    for (i, line) in content.iter().enumerate() {
+        let padding = calculate_padding(width, get_line_width_accurate(line), options.alignment);
+        let styled_line = options.render_line(line, i, extent, links, escapes);
+        grid.put_ansi(padding, options.padding_top.saturating_add(i as u16), &styled_line);
+    }
+    grid
+}
+
+/// Intervalo máximo entre comprobaciones del control de reproducción.
+const CONTROL_SLICE: Duration = Duration::from_millis(50);
+
+/// `duration` a la velocidad `speed`, sin pasar de `Duration::MAX`.
+fn at_speed(duration: Duration, speed: f32) -> Duration {
+    Duration::try_from_secs_f64(duration.as_secs_f64() / speed as f64).unwrap_or(Duration::MAX)
+}
+
+/// Espera `duration` (ajustada a la velocidad elegida) atendiendo al teclado y
+/// al control de reproducción. Mientras la reproducción está pausada, desde el
+/// teclado o con `AnimationHandle::pause()`, el tiempo no corre.
+fn wait_frame(playback: &mut Playback, duration: Duration, control: &PlaybackControl) -> Result<Step, BannerError> {
+    let mut remaining = at_speed(duration, playback.speed);
+    loop {
+        if control.is_stopped() {
+            return Ok(Step::Quit);
+        }
+        let paused = playback.paused || control.is_paused();
+        if !paused && remaining.is_zero() {
+            return Ok(Step::Next);
+        }
+        let slice = if paused { CONTROL_SLICE } else { remaining.min(CONTROL_SLICE) };
+        let started = Instant::now();
+        if poll(slice)?
+            && let Some(step) = playback.handle_event(read()?)?
+        {
+            return Ok(step);
+        }
+        if !paused {
+            remaining = remaining.saturating_sub(started.elapsed());
+        }
+    }
+}
+
+/// Equivalente asíncrono de `wait_frame` (sin control de reproducción: la
+/// cancelación se hace soltando el future).
+#[cfg(feature = "async")]
+async fn wait_frame_async(
+    playback: &mut Playback<'_, '_>,
+    duration: Duration,
+    events: &mut crossterm::event::EventStream,
+) -> Result<Step, BannerError> {
+    use futures::StreamExt;
+
+    let mut remaining = at_speed(duration, playback.speed);
+    loop {
+        let paused = playback.paused;
+        let started = Instant::now();
+        tokio::select! {
+            _ = tokio::time::sleep(remaining), if !paused => return Ok(Step::Next),
+            event = events.next() => {
+                if !paused {
+                    remaining = remaining.saturating_sub(started.elapsed());
+                }
+                match event {
+                    Some(Ok(event)) => {
+                        if let Some(step) = playback.handle_event(event)? {
+                            return Ok(step);
+                        }
+                    }
+                    Some(Err(e)) => return Err(e.into()),
+                    // Sin más eventos nadie podrá reanudar una pausa: se agota la espera y se sigue.
+                    None => {
+                        tokio::time::sleep(remaining).await;
+                        return Ok(Step::Next);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Columnas disponibles para el contenido: el ancho de la terminal (o
+/// `fallback_width` si no se conoce) menos lo que ocupan borde y panel.
+pub(crate) fn available_width(options: &RenderOptions) -> u16 {
+    let decoration = (options.content_offset().1 * 2) as u16;
+    get_terminal_width().unwrap_or(options.fallback_width).saturating_sub(decoration)
+}
+
+/// Obtiene el ancho de la terminal conectada a la salida estándar. No mira
+/// stderr ni stdin: con la salida redirigida se usa `fallback_width`.
 fn get_terminal_width() -> Result<u16, BannerError> {
-    // --- CORRECCIÓN AQUÍ ---
-    // 1. Llamar a terminal_size() y manejar el io::Result con '?'
-    let size_option: Option<(Width, Height)> = Some(terminal_size::terminal_size().expect("REASON")); // Propaga BannerError::Io si falla
-
-    // 2. Manejar el Option: si es None, devolver nuestro error específico; si es Some, extraer ancho.
-    size_option
-        .map(|(Width(w), _)| w) // Extraer ancho 'w' si es Some
-        .ok_or_else(|| {        // Si es None, crear y devolver BannerError::TerminalSize
+    terminal_size::terminal_size_of(stdout())
+        .map(|(Width(w), _)| w)
+        .ok_or_else(|| {
             BannerError::TerminalSize(io::Error::new(
-                io::ErrorKind::Unsupported, // Usar 'Unsupported' como kind para indicar no-TTY o fallo similar
+                io::ErrorKind::Unsupported, // No es una TTY o no se pudo obtener el tamaño
                 "No es una TTY o no se pudo obtener tamaño",
             ))
         })
 }
 
 
-/// Calcula el padding izquierdo necesario para centrar el contenido.
-fn calculate_padding(terminal_width: u16, content_width: u16, centered: bool) -> u16 {
-    if centered && terminal_width > content_width {
-        terminal_width.saturating_sub(content_width) / 2
-    } else {
-        0
+/// Calcula el padding izquierdo necesario según la alineación.
+fn calculate_padding(terminal_width: u16, content_width: u16, alignment: Alignment) -> u16 {
+    let free = terminal_width.saturating_sub(content_width);
+    match alignment {
+        Alignment::Left => 0,
+        Alignment::Center => free / 2,
+        Alignment::Right => free,
     }
 }
 
 /// Aplica padding vertical superior imprimiendo N líneas nuevas.
-fn apply_vertical_padding(stdout: &mut Stdout, padding: u16) -> Result<(), BannerError> {
+fn apply_vertical_padding(out: &mut impl Write, padding: u16, newline: &str) -> Result<(), BannerError> {
     if padding > 0 {
-        let padding_str = "\r\n".repeat(padding as usize);
-        queue!(stdout, crossterm_style::Print(padding_str))?;
+        let padding_str = newline.repeat(padding as usize);
+        queue!(out, crossterm_style::Print(padding_str))?;
     }
     Ok(())
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_keys_drive_playback_position() {
+        use crossterm::event::{KeyCode, KeyModifiers};
+
+        let animation = Animation::new(vec![vec!["a".into()], vec!["b".into()], vec!["c".into()]], 100).repeat(Some(3));
+        let style = Style::default();
+        let options = crate::export::plain_options(&style);
+        let mut playback = Playback::new(&animation, &options, stdout(), 80, (0, 24));
+        let key = |c| KeyEvent::new(c, KeyModifiers::NONE);
+
+        assert_eq!(playback.handle_key(key(KeyCode::Left)), Some(Step::Redraw));
+        assert_eq!((playback.frame_index, playback.paused), (2, true));
+        assert_eq!(playback.handle_key(key(KeyCode::Char(' '))), None);
+        assert!(!playback.paused);
+        playback.handle_key(key(KeyCode::Char('+')));
+        assert_eq!(playback.speed, 2.0);
+        assert_eq!(playback.handle_key(key(KeyCode::End)), Some(Step::Redraw));
+        assert_eq!((playback.frame_index, playback.current_repeat), (2, 2));
+        assert!(!playback.advance());
+        assert_eq!(playback.handle_key(key(KeyCode::Char('z'))), None);
+        assert_eq!(playback.handle_key(key(KeyCode::Esc)), Some(Step::Quit));
+    }
+
+    #[test]
+    fn test_resize_forces_full_redraw_at_new_size() {
+        let animation = Animation::new(vec![vec!["ab".into()]], 100);
+        let style = Style::default();
+        let options = RenderOptions { screen_mode: ScreenMode::Inline, ..crate::export::plain_options(&style) };
+        // Un `Vec` como salida: el test no debe escribir en la terminal.
+        let mut playback = Playback::new(&animation, &options, Vec::new(), 10, (20, 4));
+        playback.draw().unwrap();
+        playback.stdout.clear();
+
+        assert_eq!(playback.handle_event(Event::Resize(6, 22)).unwrap(), Some(Step::Redraw));
+        assert_eq!((playback.terminal_width, playback.origin_row, playback.area_height), (6, 18, 4));
+        playback.draw().unwrap();
+        let out = String::from_utf8(playback.stdout).unwrap();
+        assert!(out.starts_with("\x1b[19;1H\x1b[J"));
+        assert!(out.contains("\x1b[19;1Hab"));
+    }
+
+    #[test]
+    fn test_frames_wider_than_the_terminal_are_clipped() {
+        let animation = Animation::new(vec![vec!["abcdef".into()]], 100);
+        let style = Style::default();
+        let options = RenderOptions { screen_mode: ScreenMode::Inline, ..crate::export::plain_options(&style) };
+        // Igual al empezar que tras un redimensionado: se dibuja lo que cabe.
+        let mut playback = Playback::new(&animation, &options, Vec::new(), 4, (0, 1));
+        playback.draw().unwrap();
+        let out = String::from_utf8(playback.stdout).unwrap();
+        assert!(out.contains("abcd") && !out.contains('e'));
+    }
+
+    #[test]
+    fn test_static_content_without_terminal_uses_plain_lines() {
+        let style = Style::default();
+        let options = RenderOptions {
+            alignment: Alignment::Center,
+            padding_top: 1,
+            color_mode: ColorMode::Never,
+            ..crate::export::plain_options(&style)
+        };
+        let mut out = Vec::new();
+        write_static_content(&mut out, &vec!["ab".into(), "cdef".into()], &options, 10, false).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(), "\n    ab\n   cdef\n");
+
+        assert_eq!(FallbackFrame::First.index(3), 0);
+        assert_eq!(FallbackFrame::Last.index(3), 2);
+    }
+
+    #[test]
+    fn test_options_adapt_to_terminal_capabilities() {
+        let style = Style::default();
+        let options = RenderOptions { border: Some(BorderStyle::Rounded), hyperlink: Some("https://vornix.dev"), ..crate::export::plain_options(&style) };
+        let caps = TerminalCapabilities {
+            is_tty: true,
+            color_depth: crate::capabilities::ColorDepth::Ansi16,
+            unicode: false,
+            synchronized_output: false,
+            hyperlinks: false,
+            extended_underline: false,
+            alternate_screen: false,
+            image_protocol: None,
+            terminal_name: None,
+        };
+        let adapted = options.for_terminal(&caps);
+        assert_eq!((adapted.border, adapted.screen_mode), (Some(BorderStyle::Ascii), ScreenMode::Inline));
+        assert_eq!(adapted.render_line("ab", 0, (2, 1), &[], true), "ab");
+        assert!(options.render_line("ab", 0, (2, 1), &[], true).contains("\x1b]8;;"));
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn test_display_async_future_is_send() {
+        fn assert_send<T: Send>(_: T) {}
+        let mut banner = crate::Banner::new("x");
+        // Basta con construir el future: debe poder moverse a un `tokio::spawn`.
+        assert_send(banner.display_async());
+    }
+}
@@ -1,9 +1,11 @@
 // --- src/style.rs ---
-//! Define estilos (colores, atributos) para los banners usando `owo-colors`.
+//! Define estilos (colores, atributos, degradados) para los banners usando `owo-colors`.
 
-use owo_colors::{OwoColorize, Style as OwoStyle, DynColors, Rgb as OwoRgb};
+use owo_colors::{DynColors, Effect, OwoColorize, Rgb as OwoRgb, Style as OwoStyle};
 use std::collections::HashSet;
 
+pub use crate::color::{parse_color, ColorSpec};
+
 /// Tipo alias para colores dinámicos de `owo-colors` (incluye estándar, bright, RGB).
 pub type Color = DynColors;
 /// Tipo alias para colores RGB de `owo-colors`.
@@ -17,9 +19,72 @@ pub const FERRIS_BODY: Color = DynColors::Rgb(220, 90, 35);
 pub const FERRIS_CLAWS: Color = DynColors::Rgb(180, 70, 25);
 pub const DEFAULT_TEXT_COLOR: Color = DynColors::Rgb(64, 190, 197);
 
-/// Atributos de texto soportados.
+/// Atributos de texto soportados (SGR).
+///
+/// `DoubleUnderline` y `CurlyUnderline` usan las formas extendidas `4:2` / `4:3`;
+/// en terminales que no las soportan se degradan a un subrayado simple.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-pub enum Attribute { Bold, Italic, Underline }
+pub enum Attribute {
+    Bold,
+    Dim,
+    Italic,
+    Underline,
+    DoubleUnderline,
+    CurlyUnderline,
+    Blink,
+    Reverse,
+    Hidden,
+    Strikethrough,
+}
+
+/// Dirección en la que se interpola un degradado.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GradientDirection {
+    /// De la columna izquierda a la derecha.
+    #[default]
+    Horizontal,
+    /// De la primera línea a la última.
+    Vertical,
+}
+
+/// Degradado de color lineal entre dos o más paradas.
+///
+/// Los colores se convierten a RGB para interpolar, por lo que el resultado
+/// siempre es truecolor aunque las paradas sean colores ANSI.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Gradient {
+    pub stops: Vec<Color>,
+    pub direction: GradientDirection,
+}
+
+impl Gradient {
+    /// Crea un degradado horizontal con las paradas indicadas.
+    pub fn horizontal(stops: Vec<Color>) -> Self {
+        Self { stops, direction: GradientDirection::Horizontal }
+    }
+
+    /// Crea un degradado vertical con las paradas indicadas.
+    pub fn vertical(stops: Vec<Color>) -> Self {
+        Self { stops, direction: GradientDirection::Vertical }
+    }
+
+    /// Devuelve el color en la posición `t` (0.0 = inicio, 1.0 = final).
+    pub fn color_at(&self, t: f32) -> Color {
+        match self.stops.len() {
+            0 => DEFAULT_TEXT_COLOR,
+            1 => self.stops[0],
+            n => {
+                let t = t.clamp(0.0, 1.0) * (n - 1) as f32;
+                let index = (t.floor() as usize).min(n - 2);
+                let local = t - index as f32;
+                let (r1, g1, b1) = color_to_rgb(self.stops[index]);
+                let (r2, g2, b2) = color_to_rgb(self.stops[index + 1]);
+                let lerp = |a: u8, b: u8| (a as f32 + (b as f32 - a as f32) * local).round() as u8;
+                DynColors::Rgb(lerp(r1, r2), lerp(g1, g2), lerp(b1, b2))
+            }
+        }
+    }
+}
 
 /// Define el estilo visual de un banner.
 #[derive(Debug, Clone, Default)]
@@ -27,6 +92,10 @@ pub struct Style {
     pub foreground: Option<Color>,
     pub background: Option<Color>,
     pub attributes: HashSet<Attribute>,
+    /// Degradado de primer plano. Si está presente, tiene prioridad sobre `foreground`.
+    pub gradient: Option<Gradient>,
+    /// Color del subrayado (SGR 58). Se omite en terminales que no lo soportan.
+    pub underline_color: Option<Color>,
     owo_style: OwoStyle,
 }
 
@@ -40,10 +109,219 @@ impl Style {
     pub fn bold(mut self) -> Self { if self.attributes.insert(Attribute::Bold) { self.owo_style = self.owo_style.bold(); } self }
     pub fn italic(mut self) -> Self { if self.attributes.insert(Attribute::Italic) { self.owo_style = self.owo_style.italic(); } self }
     pub fn underline(mut self) -> Self { if self.attributes.insert(Attribute::Underline) { self.owo_style = self.owo_style.underline(); } self }
-    pub(crate) fn apply(&self, text: &str) -> String { format!("{}", text.style(self.owo_style)) }
+    pub fn dim(mut self) -> Self { if self.attributes.insert(Attribute::Dim) { self.owo_style = self.owo_style.dimmed(); } self }
+    pub fn blink(mut self) -> Self { if self.attributes.insert(Attribute::Blink) { self.owo_style = self.owo_style.blink(); } self }
+    pub fn reverse(mut self) -> Self { if self.attributes.insert(Attribute::Reverse) { self.owo_style = self.owo_style.reversed(); } self }
+    pub fn hidden(mut self) -> Self { if self.attributes.insert(Attribute::Hidden) { self.owo_style = self.owo_style.hidden(); } self }
+    pub fn strikethrough(mut self) -> Self { if self.attributes.insert(Attribute::Strikethrough) { self.owo_style = self.owo_style.strikethrough(); } self }
+    // Las formas extendidas no existen en `owo-colors`: se emiten a mano en `paint`.
+    pub fn double_underline(mut self) -> Self { self.attributes.insert(Attribute::DoubleUnderline); self }
+    pub fn curly_underline(mut self) -> Self { self.attributes.insert(Attribute::CurlyUnderline); self }
+    pub fn underline_color(mut self, color: Color) -> Self { self.underline_color = Some(color); self }
+    pub fn gradient(mut self, gradient: Gradient) -> Self { self.gradient = Some(gradient); self }
+
+    /// Activa un atributo a partir de su variante (útil al construir estilos desde datos).
+    pub fn attribute(self, attribute: Attribute) -> Self {
+        match attribute {
+            Attribute::Bold => self.bold(),
+            Attribute::Dim => self.dim(),
+            Attribute::Italic => self.italic(),
+            Attribute::Underline => self.underline(),
+            Attribute::DoubleUnderline => self.double_underline(),
+            Attribute::CurlyUnderline => self.curly_underline(),
+            Attribute::Blink => self.blink(),
+            Attribute::Reverse => self.reverse(),
+            Attribute::Hidden => self.hidden(),
+            Attribute::Strikethrough => self.strikethrough(),
+        }
+    }
+
+    pub(crate) fn apply(&self, text: &str) -> String { self.paint(text, self.owo_style) }
+
+    /// Copia del estilo sin color de fondo (para los espacios en modo transparente).
+    pub(crate) fn without_background(&self) -> Style {
+        Style { background: None, owo_style: self.owo_style.remove_bg(), ..self.clone() }
+    }
+
+    /// Subrayado doble u ondulado activo, si lo hay (el ondulado prevalece).
+    fn special_underline(&self) -> Option<&'static str> {
+        if self.attributes.contains(&Attribute::CurlyUnderline) {
+            Some("4:3")
+        } else if self.attributes.contains(&Attribute::DoubleUnderline) {
+            Some("4:2")
+        } else {
+            None
+        }
+    }
+
+    /// Secuencias SGR que `owo-colors` no sabe generar: subrayado doble/ondulado
+    /// y color de subrayado. Si la terminal no soporta las formas extendidas
+    /// (`extended` falso), los subrayados especiales se degradan a `4` y el
+    /// color se omite.
+    fn extended_sgr(&self, extended: bool) -> String {
+        let mut sgr = String::new();
+        if let Some(code) = self.special_underline() {
+            sgr.push_str(&format!("\x1b[{}m", if extended { code } else { "4" }));
+        }
+        if let (Some(color), true) = (self.underline_color, extended) {
+            let (r, g, b) = color_to_rgb(color);
+            sgr.push_str(&format!("\x1b[58;2;{};{};{}m", r, g, b));
+        }
+        sgr
+    }
+
+    /// Aplica `owo` (más las secuencias extendidas) a un fragmento de texto.
+    fn paint(&self, text: &str, owo: OwoStyle) -> String {
+        let extended = crate::capabilities::TerminalCapabilities::with_current(|caps| caps.extended_underline);
+        self.paint_with(text, owo, extended)
+    }
+
+    fn paint_with(&self, text: &str, owo: OwoStyle, extended: bool) -> String {
+        let sgr = self.extended_sgr(extended);
+        if sgr.is_empty() {
+            return format!("{}", text.style(owo));
+        }
+        // El `4` de owo anularía un `4:3`/`4:2` emitido antes: el subrayado
+        // especial sustituye al simple.
+        let owo = if self.special_underline().is_some() { owo.remove_effect(Effect::Underline) } else { owo };
+        format!("{}{}\x1b[0m", sgr, text.style(owo))
+    }
+
+    /// Aplica el estilo a un fragmento de línea situado en (`column`, `row`) dentro
+    /// de un bloque de `extent` (ancho, alto). Necesario para que los degradados
+    /// sean coherentes entre todas las líneas (y fragmentos) del banner.
+    pub(crate) fn apply_line(&self, text: &str, row: usize, column: usize, extent: (u16, u16)) -> String {
+        let Some(gradient) = &self.gradient else { return self.apply(text) };
+        let (width, height) = extent;
+        match gradient.direction {
+            GradientDirection::Vertical => {
+                let t = if height > 1 { row as f32 / (height - 1) as f32 } else { 0.0 };
+                self.paint(text, self.owo_style.color(gradient.color_at(t)))
+            }
+            GradientDirection::Horizontal => {
+                let mut out = String::new();
+                let mut column = column;
+                for ch in text.chars() {
+                    let t = if width > 1 { column as f32 / (width - 1) as f32 } else { 0.0 };
+                    let style = self.owo_style.color(gradient.color_at(t));
+                    out.push_str(&self.paint(ch.encode_utf8(&mut [0; 4]), style));
+                    column += unicode_width::UnicodeWidthChar::width(ch).unwrap_or(0);
+                }
+                out
+            }
+        }
+    }
+}
+
+/// Política de emisión de secuencias de escape (colores, atributos, hipervínculos).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ColorMode {
+    /// Emite escapes salvo que `NO_COLOR` esté definida, la salida no sea una
+    /// terminal o esta no admita color (ver `TerminalCapabilities`).
+    /// `CLICOLOR_FORCE` (distinto de `0`) fuerza su emisión.
+    #[default]
+    Auto,
+    /// Emite siempre las secuencias de escape.
+    Always,
+    /// Nunca emite secuencias: el banner se imprime como texto plano.
+    Never,
+}
+
+impl ColorMode {
+    /// Resuelve la política para la salida estándar actual.
+    pub fn escapes_enabled(self) -> bool {
+        use crate::capabilities::{ColorDepth, TerminalCapabilities};
+        match self {
+            ColorMode::Always => true,
+            ColorMode::Never => false,
+            ColorMode::Auto => {
+                let set = |name: &str| std::env::var_os(name).is_some_and(|v| !v.is_empty());
+                if std::env::var("CLICOLOR_FORCE").is_ok_and(|v| v != "0") {
+                    return true;
+                }
+                !set("NO_COLOR")
+                    && TerminalCapabilities::with_current(|caps| caps.is_tty && caps.color_depth != ColorDepth::Monochrome)
+            }
+        }
+    }
 }
 
 /// Helper para crear color RGB.
 pub fn rgb(r: u8, g: u8, b: u8) -> Color {
     DynColors::Rgb(r, g, b)
-}
\ No newline at end of file
+}
+
+/// Paleta aproximada (estilo xterm) de los 16 colores ANSI.
+const ANSI_PALETTE: [(u8, u8, u8); 16] = [
+    (0, 0, 0), (205, 0, 0), (0, 205, 0), (205, 205, 0),
+    (0, 0, 238), (205, 0, 205), (0, 205, 205), (229, 229, 229),
+    (127, 127, 127), (255, 0, 0), (0, 255, 0), (255, 255, 0),
+    (92, 92, 255), (255, 0, 255), (0, 255, 255), (255, 255, 255),
+];
+
+/// Convierte un índice de la paleta xterm de 256 colores a RGB.
+pub(crate) fn xterm_to_rgb(index: u8) -> (u8, u8, u8) {
+    match index {
+        0..=15 => ANSI_PALETTE[index as usize],
+        16..=231 => {
+            let i = index - 16;
+            let level = |v: u8| if v == 0 { 0 } else { 55 + v * 40 };
+            (level(i / 36), level((i / 6) % 6), level(i % 6))
+        }
+        _ => {
+            let gray = 8 + (index - 232) * 10;
+            (gray, gray, gray)
+        }
+    }
+}
+
+/// Convierte cualquier `Color` a su aproximación RGB.
+///
+/// `owo-colors` no expone los valores de sus paletas, así que se formatea el
+/// color como secuencia SGR y se interpreta el resultado.
+pub(crate) fn color_to_rgb(color: Color) -> (u8, u8, u8) {
+    if let DynColors::Rgb(r, g, b) = color {
+        return (r, g, b);
+    }
+    let sgr = format!("{}", " ".color(color));
+    let params: Vec<u16> = sgr
+        .trim_start_matches("\x1b[")
+        .split('m')
+        .next()
+        .unwrap_or_default()
+        .split(';')
+        .filter_map(|p| p.parse().ok())
+        .collect();
+    match params.as_slice() {
+        [38, 2, r, g, b] => (*r as u8, *g as u8, *b as u8),
+        [38, 5, i] => xterm_to_rgb(*i as u8),
+        [code @ 30..=37] => ANSI_PALETTE[(*code - 30) as usize],
+        [code @ 90..=97] => ANSI_PALETTE[(*code - 90 + 8) as usize],
+        _ => (255, 255, 255),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_attributes_emit_their_sgr_codes() {
+        let style = Style::new().dim().blink().reverse().hidden().strikethrough();
+        assert_eq!(style.paint_with("x", style.owo_style, true), "\x1b[2;5;7;8;9mx\x1b[0m");
+    }
+
+    #[test]
+    fn test_special_underlines_replace_the_plain_one() {
+        let curly = Style::new().underline().curly_underline().underline_color(rgb(1, 2, 3));
+        let out = curly.paint_with("x", curly.owo_style, true);
+        assert!(out.starts_with("\x1b[4:3m\x1b[58;2;1;2;3m"));
+        assert!(!out.contains("\x1b[4m"));
+
+        let double = Style::new().underline().double_underline();
+        assert!(double.paint_with("x", double.owo_style, true).starts_with("\x1b[4:2m"));
+        // Sin soporte extendido se degrada a un subrayado simple, sin color.
+        let plain = curly.paint_with("x", curly.owo_style, false);
+        assert!(plain.starts_with("\x1b[4m") && !plain.contains("58;"));
+    }
+}
@@ -0,0 +1,365 @@
+// --- src/theme.rs ---
+//! Temas con nombre: agrupan fuente, colores, degradado, atributos, borde y
+//! alineación para reutilizar la identidad visual entre aplicaciones.
+//!
+//! Incluye un registro con temas incrustados y, con la feature `theme_files`,
+//! carga de temas desde archivos TOML o JSON.
+
+#[cfg(feature = "theme_files")]
+use crate::error::BannerError;
+use crate::fonts::{BuiltinFont, FontSource};
+use crate::layout::{Alignment, BorderStyle};
+use crate::style::{rgb, Attribute, Color, Gradient, Style, DEFAULT_TEXT_COLOR, RUST_ORANGE};
+use std::collections::BTreeMap;
+#[cfg(feature = "theme_files")]
+use std::path::Path;
+
+/// Conjunto de opciones visuales reutilizable, aplicado con `Banner::with_theme`.
+#[derive(Debug, Clone, Default)]
+pub struct Theme {
+    pub name: String,
+    pub font: Option<FontSource>,
+    pub foreground: Option<Color>,
+    pub background: Option<Color>,
+    pub gradient: Option<Gradient>,
+    pub attributes: Vec<Attribute>,
+    pub underline_color: Option<Color>,
+    pub border: Option<BorderStyle>,
+    pub alignment: Option<Alignment>,
+}
+
+impl Theme {
+    /// Crea un tema vacío con el nombre indicado.
+    pub fn new(name: &str) -> Self {
+        Self { name: name.to_string(), ..Default::default() }
+    }
+
+    // --- Métodos Builder ---
+    pub fn font(mut self, font: FontSource) -> Self { self.font = Some(font); self }
+    pub fn builtin_font(self, font: BuiltinFont) -> Self { self.font(FontSource::Builtin(font)) }
+    pub fn fg(mut self, color: Color) -> Self { self.foreground = Some(color); self }
+    pub fn bg(mut self, color: Color) -> Self { self.background = Some(color); self }
+    pub fn gradient(mut self, gradient: Gradient) -> Self { self.gradient = Some(gradient); self }
+    pub fn attribute(mut self, attribute: Attribute) -> Self {
+        if !self.attributes.contains(&attribute) { self.attributes.push(attribute); }
+        self
+    }
+    pub fn underline_color(mut self, color: Color) -> Self { self.underline_color = Some(color); self }
+    pub fn border(mut self, border: BorderStyle) -> Self { self.border = Some(border); self }
+    pub fn alignment(mut self, alignment: Alignment) -> Self { self.alignment = Some(alignment); self }
+
+    /// Construye el `Style` equivalente a los colores y atributos del tema.
+    pub fn style(&self) -> Style {
+        let mut style = Style::new();
+        if let Some(fg) = self.foreground { style = style.fg(fg); }
+        if let Some(bg) = self.background { style = style.bg(bg); }
+        if let Some(gradient) = &self.gradient { style = style.gradient(gradient.clone()); }
+        if let Some(color) = self.underline_color { style = style.underline_color(color); }
+        self.attributes.iter().fold(style, |style, attr| style.attribute(*attr))
+    }
+
+    /// Devuelve un tema incrustado por nombre (ver `Theme::builtin_names`).
+    pub fn builtin(name: &str) -> Option<Theme> {
+        let theme = match name {
+            "vornix" => Theme::new(name).fg(DEFAULT_TEXT_COLOR).attribute(Attribute::Bold),
+            "rust" => Theme::new(name)
+                .fg(RUST_ORANGE)
+                .attribute(Attribute::Bold)
+                .builtin_font(BuiltinFont::Larry3d),
+            "sunset" => Theme::new(name)
+                .gradient(Gradient::horizontal(vec![rgb(255, 94, 77), rgb(255, 171, 64), rgb(255, 221, 87)]))
+                .attribute(Attribute::Bold),
+            "ocean" => Theme::new(name)
+                .gradient(Gradient::vertical(vec![rgb(64, 190, 197), rgb(30, 90, 200)]))
+                .border(BorderStyle::Rounded),
+            "matrix" => Theme::new(name)
+                .fg(rgb(0, 255, 65))
+                .bg(rgb(0, 0, 0))
+                .builtin_font(BuiltinFont::Small),
+            "success" => Theme::new(name).fg(rgb(80, 200, 120)).attribute(Attribute::Bold),
+            "warning" => Theme::new(name)
+                .fg(rgb(255, 200, 0))
+                .attribute(Attribute::Bold)
+                .border(BorderStyle::Heavy),
+            "error" => Theme::new(name)
+                .fg(rgb(230, 50, 50))
+                .attribute(Attribute::Bold)
+                .border(BorderStyle::Double),
+            "mono" => Theme::new(name).alignment(Alignment::Left),
+            _ => return None,
+        };
+        Some(theme)
+    }
+
+    /// Nombres de los temas incrustados.
+    pub fn builtin_names() -> &'static [&'static str] {
+        &["vornix", "rust", "sunset", "ocean", "matrix", "success", "warning", "error", "mono"]
+    }
+}
+
+/// Registro de temas indexados por nombre.
+#[derive(Debug, Clone, Default)]
+pub struct ThemeRegistry {
+    themes: BTreeMap<String, Theme>,
+}
+
+impl ThemeRegistry {
+    /// Crea un registro vacío.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Crea un registro que contiene todos los temas incrustados.
+    pub fn with_builtins() -> Self {
+        let mut registry = Self::new();
+        for name in Theme::builtin_names() {
+            if let Some(theme) = Theme::builtin(name) {
+                registry.register(theme);
+            }
+        }
+        registry
+    }
+
+    /// Registra (o reemplaza) un tema usando su nombre como clave.
+    pub fn register(&mut self, theme: Theme) {
+        self.themes.insert(theme.name.clone(), theme);
+    }
+
+    /// Busca un tema por nombre.
+    pub fn get(&self, name: &str) -> Option<&Theme> {
+        self.themes.get(name)
+    }
+
+    /// Nombres registrados, en orden alfabético.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.themes.keys().map(String::as_str)
+    }
+
+    /// Carga los temas de un archivo `.toml` o `.json` y los añade al registro.
+    /// Devuelve el número de temas cargados.
+    ///
+    /// Las rutas de fuentes relativas se resuelven respecto al directorio del archivo.
+    #[cfg(feature = "theme_files")]
+    pub fn load_file(&mut self, path: impl AsRef<Path>) -> Result<usize, BannerError> {
+        let path = path.as_ref();
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| BannerError::ThemeLoad(format!("No se pudo leer {:?}: {}", path, e)))?;
+        let file: files::ThemeFile = match path.extension().and_then(|e| e.to_str()) {
+            Some("json") => files::parse_json(&content)?,
+            Some("toml") => files::parse_toml(&content)?,
+            _ => files::parse_toml(&content).or_else(|_| files::parse_json(&content))?,
+        };
+        self.insert_file(file, path.parent())
+    }
+
+    /// Añade los temas definidos en un documento TOML.
+    #[cfg(feature = "theme_files")]
+    pub fn load_toml_str(&mut self, content: &str) -> Result<usize, BannerError> {
+        let file = files::parse_toml(content)?;
+        self.insert_file(file, None)
+    }
+
+    /// Añade los temas definidos en un documento JSON.
+    #[cfg(feature = "theme_files")]
+    pub fn load_json_str(&mut self, content: &str) -> Result<usize, BannerError> {
+        let file = files::parse_json(content)?;
+        self.insert_file(file, None)
+    }
+
+    #[cfg(feature = "theme_files")]
+    fn insert_file(&mut self, file: files::ThemeFile, base_dir: Option<&Path>) -> Result<usize, BannerError> {
+        let count = file.themes.len();
+        for (name, spec) in file.themes {
+            let theme = spec.into_theme(&name, base_dir)?;
+            self.register(theme);
+        }
+        Ok(count)
+    }
+}
+
+/// Formato de archivo de temas (requiere la feature `theme_files`).
+///
+/// ```toml
+/// [themes.brand]
+/// font = "slant"                    # fuente incrustada o ruta a un .flf
+/// fg = "#40bec5"                    # cualquier formato aceptado por `parse_color`
+/// bg = "rgb(30, 30, 30)"
+/// gradient = ["tomato", "#ffdd57"]
+/// gradient_direction = "horizontal" # o "vertical"
+/// attributes = ["bold", "curly_underline"] # bold, dim, italic, underline, double_underline,
+///                                         # curly_underline, blink, reverse, hidden, strikethrough
+/// underline_color = "tomato"
+/// border = "rounded"                # single, double, rounded, heavy, ascii
+/// alignment = "center"              # left, center, right
+/// ```
+#[cfg(feature = "theme_files")]
+mod files {
+    use super::*;
+    use crate::style::{parse_color, GradientDirection};
+    use serde::Deserialize;
+    use std::collections::HashMap;
+
+    #[derive(Debug, Deserialize)]
+    pub(super) struct ThemeFile {
+        #[serde(default)]
+        pub themes: HashMap<String, ThemeSpec>,
+    }
+
+    #[derive(Debug, Default, Deserialize)]
+    #[serde(default, deny_unknown_fields)]
+    pub(super) struct ThemeSpec {
+        font: Option<String>,
+        fg: Option<String>,
+        bg: Option<String>,
+        gradient: Option<Vec<String>>,
+        gradient_direction: Option<String>,
+        attributes: Vec<String>,
+        underline_color: Option<String>,
+        border: Option<String>,
+        alignment: Option<String>,
+    }
+
+    pub(super) fn parse_toml(content: &str) -> Result<ThemeFile, BannerError> {
+        toml::from_str(content).map_err(|e| BannerError::ThemeLoad(format!("TOML inválido: {}", e)))
+    }
+
+    pub(super) fn parse_json(content: &str) -> Result<ThemeFile, BannerError> {
+        serde_json::from_str(content).map_err(|e| BannerError::ThemeLoad(format!("JSON inválido: {}", e)))
+    }
+
+    impl ThemeSpec {
+        pub(super) fn into_theme(self, name: &str, base_dir: Option<&Path>) -> Result<Theme, BannerError> {
+            let err = |field: &str, value: &str| {
+                BannerError::ThemeLoad(format!("Tema '{}': valor inválido para '{}': '{}'", name, field, value))
+            };
+
+            let mut theme = Theme::new(name);
+            if let Some(font) = self.font {
+                theme.font = Some(match parse_builtin_font(&font) {
+                    Some(builtin) => FontSource::Builtin(builtin),
+                    None => FontSource::File(match base_dir {
+                        Some(dir) => dir.join(&font),
+                        None => font.into(),
+                    }),
+                });
+            }
+            let color = |field: &str, value: &str| {
+                parse_color(value).map_err(|e| BannerError::ThemeLoad(format!("Tema '{}', campo '{}': {}", name, field, e)))
+            };
+            if let Some(fg) = self.fg {
+                theme.foreground = Some(color("fg", &fg)?);
+            }
+            if let Some(bg) = self.bg {
+                theme.background = Some(color("bg", &bg)?);
+            }
+            if let Some(stops) = self.gradient {
+                let stops = stops
+                    .iter()
+                    .map(|s| color("gradient", s))
+                    .collect::<Result<Vec<_>, _>>()?;
+                let direction = match self.gradient_direction.as_deref() {
+                    None | Some("horizontal") => GradientDirection::Horizontal,
+                    Some("vertical") => GradientDirection::Vertical,
+                    Some(other) => return Err(err("gradient_direction", other)),
+                };
+                theme.gradient = Some(Gradient { stops, direction });
+            }
+            for attr in &self.attributes {
+                let attribute = match attr.as_str() {
+                    "bold" => Attribute::Bold,
+                    "dim" => Attribute::Dim,
+                    "italic" => Attribute::Italic,
+                    "underline" => Attribute::Underline,
+                    "double_underline" => Attribute::DoubleUnderline,
+                    "curly_underline" => Attribute::CurlyUnderline,
+                    "blink" => Attribute::Blink,
+                    "reverse" => Attribute::Reverse,
+                    "hidden" => Attribute::Hidden,
+                    "strikethrough" => Attribute::Strikethrough,
+                    other => return Err(err("attributes", other)),
+                };
+                theme = theme.attribute(attribute);
+            }
+            if let Some(underline_color) = self.underline_color {
+                theme.underline_color = Some(color("underline_color", &underline_color)?);
+            }
+            if let Some(border) = self.border {
+                theme.border = Some(match border.as_str() {
+                    "single" => BorderStyle::Single,
+                    "double" => BorderStyle::Double,
+                    "rounded" => BorderStyle::Rounded,
+                    "heavy" => BorderStyle::Heavy,
+                    "ascii" => BorderStyle::Ascii,
+                    other => return Err(err("border", other)),
+                });
+            }
+            if let Some(alignment) = self.alignment {
+                theme.alignment = Some(match alignment.as_str() {
+                    "left" => Alignment::Left,
+                    "center" => Alignment::Center,
+                    "right" => Alignment::Right,
+                    other => return Err(err("alignment", other)),
+                });
+            }
+            Ok(theme)
+        }
+    }
+
+    fn parse_builtin_font(name: &str) -> Option<BuiltinFont> {
+        match name.to_ascii_lowercase().as_str() {
+            "standard" => Some(BuiltinFont::Standard),
+            "slant" => Some(BuiltinFont::Slant),
+            "small" => Some(BuiltinFont::Small),
+            "block" => Some(BuiltinFont::Block),
+            "larry3d" => Some(BuiltinFont::Larry3d),
+            "lean" => Some(BuiltinFont::Lean),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_registry_contains_builtins() {
+        let registry = ThemeRegistry::with_builtins();
+        for name in Theme::builtin_names() {
+            assert!(registry.get(name).is_some(), "falta el tema {}", name);
+        }
+        assert!(Theme::builtin("inexistente").is_none());
+    }
+
+    #[cfg(feature = "theme_files")]
+    #[test]
+    fn test_load_toml_and_json() {
+        let mut registry = ThemeRegistry::new();
+        let toml = r##"
+            [themes.brand]
+            font = "slant"
+            fg = "#40bec5"
+            attributes = ["bold"]
+            border = "rounded"
+            alignment = "right"
+        "##;
+        assert_eq!(registry.load_toml_str(toml).unwrap(), 1);
+        let brand = registry.get("brand").unwrap();
+        assert!(matches!(brand.font, Some(FontSource::Builtin(BuiltinFont::Slant))));
+        assert_eq!(brand.foreground, Some(rgb(0x40, 0xbe, 0xc5)));
+        assert_eq!(brand.border, Some(BorderStyle::Rounded));
+        assert_eq!(brand.alignment, Some(Alignment::Right));
+
+        let json = r##"{ "themes": { "alert": { "gradient": ["#ff0000", "#0000ff"], "gradient_direction": "vertical" } } }"##;
+        assert_eq!(registry.load_json_str(json).unwrap(), 1);
+        assert_eq!(registry.get("alert").unwrap().gradient.as_ref().unwrap().stops.len(), 2);
+    }
+
+    #[cfg(feature = "theme_files")]
+    #[test]
+    fn test_invalid_value_is_reported() {
+        let mut registry = ThemeRegistry::new();
+        let err = registry.load_toml_str("[themes.x]\nborder = \"zigzag\"").unwrap_err();
+        assert!(matches!(err, BannerError::ThemeLoad(msg) if msg.contains("zigzag")));
+    }
+}
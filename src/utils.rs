@@ -5,11 +5,12 @@ use unicode_width::UnicodeWidthStr;
 use regex::Regex;
 use once_cell::sync::Lazy;
 
-// Regex para encontrar códigos de escape ANSI. Compilado una sola vez.
+// Regex para encontrar códigos de escape ANSI (CSI y OSC, incluidos los
+// hipervínculos OSC 8 terminados en ST). Compilado una sola vez.
 // Fuente: https://github.com/chalk/ansi-regex/blob/main/index.js adaptado a Rust
 static ANSI_REGEX: Lazy<Regex> = Lazy::new(|| {
     Regex::new(
-        r"[\u001B\u009B][\[\]()#;?]*((([a-zA-Z\d;]*[a-zA-Z\d]?)\u0007)|((\d{1,4}(?:;\d{0,4})*)?[\dA-PR-TZcf-ntqry=><~]))"
+        r"\u001B\][^\u0007\u001B]*(?:\u0007|\u001B\\)|[\u001B\u009B][\[\]()#;?]*((([a-zA-Z\d;]*[a-zA-Z\d]?)\u0007)|((\d{1,4}(?:[;:]\d{0,4})*)?[\dA-PR-TZcf-ntqry=><~]))"
     ).unwrap()
 });
 
@@ -32,6 +33,35 @@ pub(crate) fn get_line_width_accurate(text: &str) -> u16 {
     UnicodeWidthStr::width(cleaned_text.as_ref()) as u16
 }
 
+/// Generador pseudoaleatorio mínimo (SplitMix64) para efectos reproducibles
+/// a partir de una semilla, sin depender de crates externos.
+#[derive(Debug, Clone)]
+pub(crate) struct Rng(u64);
+
+impl Rng {
+    pub(crate) fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    pub(crate) fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Número en `[0.0, 1.0)`.
+    pub(crate) fn next_f32(&mut self) -> f32 {
+        (self.next_u64() >> 40) as f32 / (1u64 << 24) as f32
+    }
+
+    /// Entero en `[0, max)`. `max` debe ser mayor que cero.
+    pub(crate) fn below(&mut self, max: usize) -> usize {
+        (self.next_u64() % max as u64) as usize
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -59,6 +89,11 @@ mod tests {
         assert_eq!(get_line_width_accurate(&complex_styled), 4 + 1 + 5); // "你好 world"
     }
 
+    #[test]
+    fn test_width_with_extended_sgr() {
+        assert_eq!(get_line_width_accurate("\u{1b}[4:3m\u{1b}[58;2;255;0;0mhola\u{1b}[0m"), 4);
+    }
+
      #[test]
     fn test_width_empty_string() {
         assert_eq!(get_line_width_accurate(""), 0);